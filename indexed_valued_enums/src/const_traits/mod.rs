@@ -0,0 +1,74 @@
+use crate::indexed_enum::{discriminant_internal_sized, first_internal, from_discriminant_internal, from_discriminant_opt_internal, Indexed, last_internal};
+use crate::valued_enum::{value_internal, value_opt_internal, value_ref_internal, value_ref_opt_internal, Valued};
+
+/// Const version of the [Indexed] trait, whose functions can be called in const contexts, both
+/// on the enum itself and on generic parameters bound by `[const] ConstIndexed`.
+///
+/// It's implemented as `impl const` through the extra feature 'ConstTraitImpls' or the attribute
+/// #[const_trait_impls], which requires the nightly feature 'const_trait_impl'.
+///
+/// Note that as these functions share their names with those of [Indexed], calling them through
+/// the method syntax is ambiguous when both traits are in scope, which is always the case for
+/// generic parameters bound by [ConstIndexed], in those cases call them like
+/// `ConstIndexed::discriminant(&variant)`.
+pub const trait ConstIndexed: Indexed {
+    /// Gets the discriminant of this variant, this operation is O(1)
+    fn discriminant(&self) -> usize {
+        discriminant_internal_sized(self)
+    }
+
+    /// Gets the variant corresponding to said discriminant, this operation is O(1) as it just
+    /// gets the variant as a copy from [Indexed::VARIANTS]
+    fn from_discriminant_opt(discriminant: usize) -> Option<Self> {
+        from_discriminant_opt_internal(discriminant)
+    }
+
+    /// Gets the variant corresponding to said discriminant, this operation is O(1) as it just
+    /// gets the variant as a copy from [Indexed::VARIANTS], panicking if there is no variant with
+    /// such discriminant
+    fn from_discriminant(discriminant: usize) -> Self {
+        from_discriminant_internal(discriminant)
+    }
+
+    /// Gets the variant with the lowest discriminant, this operation is O(1)
+    fn first() -> Option<Self> {
+        first_internal()
+    }
+
+    /// Gets the variant with the highest discriminant, this operation is O(1)
+    fn last() -> Option<Self> {
+        last_internal()
+    }
+}
+
+/// Const version of the [Valued] trait, whose functions can be called in const contexts, both on
+/// the enum itself and on generic parameters bound by `[const] ConstValued`.
+///
+/// It's implemented as `impl const` through the extra feature 'ConstTraitImpls' or the attribute
+/// #[const_trait_impls], which requires the nightly feature 'const_trait_impl'.
+///
+/// As its functions read [Valued::VALUES] directly, values overridden through the feature
+/// 'value_override' aren't seen by them.
+pub const trait ConstValued: Valued + ConstIndexed {
+    /// Gives the value corresponding to this variant, this is an O(1) operation as it just gets
+    /// the value as a copy from [Valued::VALUES]
+    fn value_opt(&self) -> Option<Self::Value> where Self::Value: Copy {
+        value_opt_internal(self)
+    }
+
+    /// Gives the value corresponding to this variant, this is an O(1) operation as it just gets
+    /// the value as a copy from [Valued::VALUES]
+    fn value(&self) -> Self::Value where Self::Value: Copy {
+        value_internal(self)
+    }
+
+    /// Gives a reference to the value corresponding to this variant, this is an O(1) operation
+    fn value_ref_opt(&self) -> Option<&'static Self::Value> {
+        value_ref_opt_internal(self)
+    }
+
+    /// Gives a reference to the value corresponding to this variant, this is an O(1) operation
+    fn value_ref(&self) -> &'static Self::Value {
+        value_ref_internal(self)
+    }
+}
@@ -1,3 +1,9 @@
+use core::iter::{FusedIterator, Skip, Zip};
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::map::EnumMap;
+
 /// Allows to get a discriminant from an enum's variant to an usize, and also get the same variant
 /// from said discriminant, for example, having the following implementation:
 ///
@@ -22,9 +28,25 @@ pub trait Indexed: Sized + 'static {
     /// Array storing all the variants of the enum ordered by discriminant.
     const VARIANTS: &'static [Self];
 
+    /// Amount of variants of the enum, unlike calling len() on [Indexed::VARIANTS], this can be used
+    /// wherever a const is needed, like the length of an array as in `[0u32; Number::VARIANT_COUNT]`.
+    const VARIANT_COUNT: usize = Self::VARIANTS.len();
+
+    /// Whether none of the variants of the enum have fields, this allows generic code to pick
+    /// different paths at compile time depending on it.
+    ///
+    /// Both [crate::Valued] and [crate::create_indexed_valued_enum] set this, but when implemented
+    /// manually it defaults to false, as it can't be known whether the enum has fields.
+    const IS_FIELD_LESS: bool = false;
+
+    /// Size in bytes of the enum's discriminant as stored in memory, which is the size of the type
+    /// in its #[repr(...)] attribute, it defaults to the size of usize as the enums are attributed
+    /// with #[repr(usize)], unless a different width is chosen through #[discriminant_repr(...)].
+    const DISCRIMINANT_SIZE: usize = core::mem::size_of::<usize>();
+
     /// Gets the discriminant of this variant, this operation is O(1).
     fn discriminant(&self) -> usize {
-        discriminant_internal(self)
+        discriminant_internal_sized(self)
     }
 
     /// Gets the variant corresponding to said discriminant, this operation is O(1) as it just gets
@@ -47,15 +69,211 @@ pub trait Indexed: Sized + 'static {
     fn from_discriminant(discriminant: usize) -> Self {
         from_discriminant_opt_internal(discriminant).unwrap()
     }
+
+    /// Gets the variant corresponding to said discriminant, this operation is O(1) as it just gets
+    /// the discriminant as a read-copy from [Indexed::VARIANTS].
+    ///
+    /// Unlike [Indexed::from_discriminant], this doesn't panic when the discriminant is a number
+    /// larger than [Indexed::VARIANTS]'s length, but it gives a [DiscriminantOutOfRange] error,
+    /// which is useful when the discriminant comes from untrusted input.
+    fn try_from_discriminant(discriminant: usize) -> Result<Self, DiscriminantOutOfRange> {
+        from_discriminant_opt_internal(discriminant)
+            .ok_or(DiscriminantOutOfRange { discriminant, variant_count: Self::VARIANT_COUNT })
+    }
+
+    /// Gets the variant corresponding to said discriminant, just like
+    /// [Indexed::from_discriminant_opt], but only accepting discriminants lower than this maximum,
+    /// giving [None] when it's reached even if there is a variant with such discriminant.
+    ///
+    /// This is useful when the peer sending discriminants only knows the first variants of the
+    /// enum, like an older version of a protocol, to reject the variants it shouldn't send.
+    fn from_discriminant_bounded(discriminant: usize, max: usize) -> Option<Self> {
+        if discriminant >= max { return None; }
+        from_discriminant_opt_internal(discriminant)
+    }
+
+    /// Gets a static reference to the variant corresponding to said discriminant, this operation is
+    /// O(1) as it just borrows the variant from [Indexed::VARIANTS] without copying it, being ideal
+    /// for large variants with fields.
+    ///
+    /// When the discriminant is a number larger than [Indexed::VARIANTS]'s length, it gives a
+    /// [DiscriminantOutOfRange] error.
+    fn try_ref_from_discriminant(discriminant: usize) -> Result<&'static Self, DiscriminantOutOfRange> {
+        Self::VARIANTS.get(discriminant)
+            .ok_or(DiscriminantOutOfRange { discriminant, variant_count: Self::VARIANT_COUNT })
+    }
+
+    /// Gets the variant with the lowest discriminant, or [None] if the enum doesn't have any
+    /// variant, this operation is O(1).
+    ///
+    /// A const version of this function is given by the 'Delegators' feature.
+    fn first() -> Option<Self> {
+        first_internal()
+    }
+
+    /// Gets the variant with the highest discriminant, or [None] if the enum doesn't have any
+    /// variant, this operation is O(1).
+    ///
+    /// A const version of this function is given by the 'Delegators' feature.
+    fn last() -> Option<Self> {
+        last_internal()
+    }
+
+    /// Gets the variant whose discriminant follows this variant's one, or [None] if this is the
+    /// last variant, this operation is O(1) and doesn't require the enum to implement [Clone].
+    fn next_variant(&self) -> Option<Self> {
+        from_discriminant_opt_internal(self.discriminant() + 1)
+    }
+
+    /// Gets the variant whose discriminant precedes this variant's one, or [None] if this is the
+    /// first variant, this operation is O(1) and doesn't require the enum to implement [Clone].
+    fn previous_variant(&self) -> Option<Self> {
+        from_discriminant_opt_internal(self.discriminant().checked_sub(1)?)
+    }
+
+    /// Gets both the variant preceding and the variant following this variant's one as
+    /// (previous, next), each being [None] when this is the first or last variant respectively,
+    /// this operation is O(1) and doesn't require the enum to implement [Clone].
+    ///
+    /// A const version of this function is given by the 'Delegators' feature.
+    fn neighbors(&self) -> (Option<Self>, Option<Self>) {
+        neighbors_internal(self)
+    }
+
+    /// Gets the variant whose discriminant follows this variant's one, going back to the first
+    /// variant when this is the last one, this operation is O(1).
+    fn wrapping_next(&self) -> Self {
+        Self::from_discriminant((self.discriminant() + 1) % Self::VARIANT_COUNT)
+    }
+
+    /// Gets the variant whose discriminant precedes this variant's one, going to the last variant
+    /// when this is the first one, this operation is O(1).
+    fn wrapping_prev(&self) -> Self {
+        Self::from_discriminant((self.discriminant() + Self::VARIANT_COUNT - 1) % Self::VARIANT_COUNT)
+    }
+
+    /// Iterates over every variant of this enum in order of discriminant, this doesn't require the
+    /// enum to implement [Clone], as each variant is read from [Indexed::VARIANTS] through
+    /// [core::ptr::read].
+    fn iter() -> VariantsIter<Self> {
+        VariantsIter { discriminants: 0..Self::VARIANT_COUNT, _indexed: PhantomData }
+    }
+
+    /// Iterates over every variant paired with the variant following it in order of discriminant,
+    /// like [(A, B), (B, C), (C, D)], which is useful to build the edges of a linear state machine,
+    /// this doesn't require the enum to implement [Clone], as each variant is read twice from
+    /// [Indexed::VARIANTS].
+    ///
+    /// Enums with less than two variants give an empty iterator.
+    fn adjacent_pairs() -> Zip<VariantsIter<Self>, Skip<VariantsIter<Self>>> {
+        Self::iter().zip(Self::iter().skip(1))
+    }
+
+    /// Creates an [EnumMap] associating a value to every variant of this enum, where each value is
+    /// the result of calling the function on said variant, this is useful to associate data to
+    /// variants when it can't be const.
+    ///
+    /// The const parameter N must be [Indexed::VARIANT_COUNT].
+    fn remap<T, TFunction: FnMut(Self) -> T, const N: usize>(function: TFunction) -> EnumMap<Self, T, N> {
+        EnumMap::from_fn(function)
+    }
 }
 
+/// Iterator over every variant of an enum implementing [Indexed] in order of discriminant, created
+/// through [Indexed::iter].
+pub struct VariantsIter<TIndexed: Indexed> {
+    discriminants: Range<usize>,
+    _indexed: PhantomData<TIndexed>,
+}
+
+impl<TIndexed: Indexed> Iterator for VariantsIter<TIndexed> {
+    type Item = TIndexed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        from_discriminant_opt_internal(self.discriminants.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.discriminants.size_hint()
+    }
+}
+
+impl<TIndexed: Indexed> DoubleEndedIterator for VariantsIter<TIndexed> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        from_discriminant_opt_internal(self.discriminants.next_back()?)
+    }
+}
+
+impl<TIndexed: Indexed> ExactSizeIterator for VariantsIter<TIndexed> {}
+
+impl<TIndexed: Indexed> FusedIterator for VariantsIter<TIndexed> {}
+
+/// Error produced when trying to get a variant from a discriminant that doesn't correspond to any
+/// variant, as it's equal or larger than the amount of variants.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DiscriminantOutOfRange {
+    /// Discriminant that doesn't correspond to any variant.
+    pub discriminant: usize,
+    /// Amount of variants of the enum, see [Indexed::VARIANT_COUNT].
+    pub variant_count: usize,
+}
+
+impl core::fmt::Display for DiscriminantOutOfRange {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "Discriminant {} doesn't correspond to any variant, as there are only {} variants",
+               self.discriminant, self.variant_count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DiscriminantOutOfRange {}
+
+/// Unsigned integer types an enum's discriminant can be represented as through
+/// ##[discriminant_repr(...)], as discriminants are read back as unsigned integers of their size.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a discriminant, ##[discriminant_repr(...)] only accepts u8, u16, u32, u64 or usize",
+    label = "not u8, u16, u32, u64 or usize"
+)]
+pub trait DiscriminantRepr: Sized {
+    /// Size in bytes of this type, which becomes the [Indexed::DISCRIMINANT_SIZE] of the enum.
+    const SIZE: usize = core::mem::size_of::<Self>();
+}
+
+impl DiscriminantRepr for u8 {}
+
+impl DiscriminantRepr for u16 {}
+
+impl DiscriminantRepr for u32 {}
+
+impl DiscriminantRepr for u64 {}
+
+impl DiscriminantRepr for usize {}
+
 /// Gets the discriminant for a variant of an enum marked with #[repr(usize)], this operation is O(1).
 ///
-/// This internal function is used when using 'Delegators'.
+/// As it always reads an usize, enums whose discriminant is of another type, like those using
+/// #[discriminant_repr(u8)], must use [discriminant_internal_sized] instead.
 pub const fn discriminant_internal<T>(variant: &T) -> usize {
     unsafe { *(variant as *const T).cast::<usize>() }
 }
 
+/// Gets the discriminant for a variant of an enum marked with #[repr(usize)], or the type whose
+/// size is [Indexed::DISCRIMINANT_SIZE], this operation is O(1).
+///
+/// This internal function is used when using 'Delegators'.
+pub const fn discriminant_internal_sized<TIndexed: Indexed>(variant: &TIndexed) -> usize {
+    let discriminant = (variant as *const TIndexed).cast::<u8>();
+    unsafe {
+        match TIndexed::DISCRIMINANT_SIZE {
+            1 => *discriminant as usize,
+            2 => *discriminant.cast::<u16>() as usize,
+            4 => *discriminant.cast::<u32>() as usize,
+            8 => *discriminant.cast::<u64>() as usize,
+            _ => *discriminant.cast::<usize>(),
+        }
+    }
+}
+
 /// Gets the variant corresponding to said discriminant, this operation is O(1) as it just gets
 /// the discriminant as a read-copy from [Indexed::VARIANTS].
 ///
@@ -83,6 +301,38 @@ pub const fn from_discriminant_opt_internal<TIndexed: Indexed>(discriminant: usi
     Some(unsafe { TIndexed::VARIANTS.as_ptr().offset(first_offset).offset(second_offset).offset(third_offset).read() })
 }
 
+/// Gets the variant with the lowest discriminant, or [None] if the enum doesn't have any variant,
+/// this operation is O(1).
+///
+/// This internal function is used when using 'Delegators'.
+pub const fn first_internal<TIndexed: Indexed>() -> Option<TIndexed> {
+    from_discriminant_opt_internal(0)
+}
+
+/// Gets the variant with the highest discriminant, or [None] if the enum doesn't have any variant,
+/// this operation is O(1).
+///
+/// This internal function is used when using 'Delegators'.
+pub const fn last_internal<TIndexed: Indexed>() -> Option<TIndexed> {
+    match TIndexed::VARIANT_COUNT.checked_sub(1) {
+        Some(discriminant) => from_discriminant_opt_internal(discriminant),
+        None => None,
+    }
+}
+
+/// Gets both the variant preceding and the variant following this variant's one as
+/// (previous, next), each being [None] when this is the first or last variant respectively, this
+/// operation is O(1).
+///
+/// This internal function is used when using 'Delegators'.
+pub const fn neighbors_internal<TIndexed: Indexed>(variant: &TIndexed) -> (Option<TIndexed>, Option<TIndexed>) {
+    let discriminant = discriminant_internal_sized(variant);
+    let previous = match discriminant.checked_sub(1) {
+        Some(previous_discriminant) => from_discriminant_opt_internal(previous_discriminant),
+        None => None,
+    };
+    (previous, from_discriminant_opt_internal(discriminant + 1))
+}
 
 /// Divides an usize in three isizes whose sums results in the original usize, used to point on the
 /// arrays of [Indexed::VARIANTS] and [super::Valued::VALUES] .
@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+use crate::indexed_enum::Indexed;
+
+/// Table associating a value of type T to every variant of an enum implementing [Indexed], just
+/// like [crate::map::EnumMap], but storing the values on the heap, which is useful when the values
+/// are large or the enum has many variants, and as it isn't an array, it doesn't need the amount
+/// of variants as a const parameter:
+///
+/// ```rust
+/// use indexed_valued_enums::{create_indexed_valued_enum, indexed_vec::IndexedVec};
+///
+/// create_indexed_valued_enum! {
+///     #[derive(PartialEq, Debug)]
+///     enum Planet valued as f32;
+///     Earth, 9.807,
+///     Mars, 3.71,
+///     Mercury, 3.7
+/// }
+///
+/// let mut visitors = IndexedVec::<Planet, Vec<&str>>::from_fn(|_| Vec::new());
+/// visitors[Planet::Mars].push("Curiosity");
+/// assert_eq!(visitors[Planet::Mars], vec!["Curiosity"]);
+/// assert!(visitors[Planet::Earth].is_empty());
+/// ```
+///
+/// This requires the feature 'alloc' or 'std'.
+pub struct IndexedVec<TIndexed: Indexed, T> {
+    values: Vec<T>,
+    _indexed: PhantomData<TIndexed>,
+}
+
+impl<TIndexed: Indexed, T> IndexedVec<TIndexed, T> {
+    /// Creates a table where every variant is associated to a clone of this value.
+    pub fn new(value: T) -> Self where T: Clone {
+        Self { values: alloc::vec![value; TIndexed::VARIANT_COUNT], _indexed: PhantomData }
+    }
+
+    /// Creates a table by calling the function on every variant in order of discriminant, storing
+    /// each result as the value of said variant.
+    pub fn from_fn<TFunction: FnMut(TIndexed) -> T>(function: TFunction) -> Self {
+        Self { values: TIndexed::iter().map(function).collect(), _indexed: PhantomData }
+    }
+
+    /// Creates a table from a vector of values, where each value is stored in the same position as
+    /// the discriminant of the variant they belong to, giving back the vector if it doesn't have
+    /// as many values as variants.
+    pub fn from_vec(values: Vec<T>) -> Result<Self, Vec<T>> {
+        if values.len() != TIndexed::VARIANT_COUNT { return Err(values); }
+        Ok(Self { values, _indexed: PhantomData })
+    }
+
+    /// Gets a reference to the value associated to this variant, this operation is O(1).
+    pub fn get(&self, variant: &TIndexed) -> &T {
+        &self.values[variant.discriminant()]
+    }
+
+    /// Gets a mutable reference to the value associated to this variant, this operation is O(1).
+    pub fn get_mut(&mut self, variant: &TIndexed) -> &mut T {
+        &mut self.values[variant.discriminant()]
+    }
+
+    /// Gives the values of this table, where each value is stored in the same position as the
+    /// discriminant of the variant they belong to.
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Consumes this table giving back it's values, where each value is stored in the same
+    /// position as the discriminant of the variant they belong to.
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+
+    /// Iterates over every variant along it's associated value in order of discriminant.
+    pub fn iter(&self) -> impl Iterator<Item=(TIndexed, &T)> {
+        self.values.iter()
+            .enumerate()
+            .map(|(discriminant, value)| (TIndexed::from_discriminant(discriminant), value))
+    }
+}
+
+impl<TIndexed: Indexed, T: Clone> Clone for IndexedVec<TIndexed, T> {
+    fn clone(&self) -> Self {
+        Self { values: self.values.clone(), _indexed: PhantomData }
+    }
+}
+
+impl<TIndexed: Indexed, T> Index<TIndexed> for IndexedVec<TIndexed, T> {
+    type Output = T;
+
+    fn index(&self, variant: TIndexed) -> &Self::Output {
+        self.get(&variant)
+    }
+}
+
+impl<TIndexed: Indexed, T> IndexMut<TIndexed> for IndexedVec<TIndexed, T> {
+    fn index_mut(&mut self, variant: TIndexed) -> &mut Self::Output {
+        self.get_mut(&variant)
+    }
+}
@@ -1,5 +1,6 @@
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![cfg_attr(feature = "const_trait", feature(const_trait_impl))]
 
 //! [![crates.io](https://img.shields.io/crates/v/indexed_valued_enums.svg)](https://crates.io/crates/indexed_valued_enums)
 //! [![GitHub Actions Workflow Status](https://img.shields.io/github/actions/workflow/status/JorgeRicoVivas/indexed_valued_enums/rust.yml)](https://github.com/JorgeRicoVivas/indexed_valued_enums/actions)
@@ -23,6 +24,7 @@
 //! &nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;2.b.3 [Other examples for the derive macro](#2b3-other-examples-for-the-derive-macro)<br>
 //! 3 [Extra features](#3-extra-features)<br>
 //! 4 [Assumptions this crate does](#4-assumptions-this-crate-does)<br>
+//! 5 [Breaking changes](#5-breaking-changes)<br>
 //!
 //! ## 1 Motivation and use
 //! In a few programming languages it is possible to create enums and associate some information
@@ -94,7 +96,7 @@
 //!     assert_eq!(Planet::Mercury.discriminant(), 2);
 //!
 //!     //Value mechanics
-//!     assert_eq!(Planet::Earth.value().radius, 6357.0);
+//!     assert_eq!(Planet::Earth.value_ref().radius, 6357.0);
 //!     assert_eq!(Planet::Mars.gravity, 3.71);
 //!     assert_eq!(Planet::Mercury, Planet::value_to_variant(&CelestialBody{ radius: 2439.7, gravity: 3.7 }));
 //! }
@@ -105,8 +107,7 @@
 //! On this one you write every variant along it's value, being really easy to write and read, and
 //! especially useful when creating simple enums without a lot of manipulation, be them short or
 //! large however, in case where you need to directly manipulate your enum, it can be quite
-//! restrictive and it doesn't support variants with fields, be them named or unnamed, if you find
-//! yourself in any of these two scenarios, use the derive macro instead.
+//! restrictive, if you find yourself in this scenario, use the derive macro instead.
 //! <br><br>
 //! * [The Derive macro](#2b1-introductory-example-of-valued-enum-use-via-the-derive-macro): On this
 //! one you only need to add a few attributes to your enum and your variants indicating the values,
@@ -116,6 +117,9 @@
 //! on your Cargo.toml, like
 //! ```indexed_valued_enums = { version =  "1.0.0", features=["derive", ...] }```.
 //!
+//! If your variants don't have meaningful values, the declarative macro create_indexed_enum! creates
+//! enums implementing just the Indexed trait, written like the declarative macro but without values.
+//!
 //! ## 2.a.1 Introductory example of valued enum use via the declarative macro
 //! This creates a public enum where every Number has an associated value of type NumberDescription,
 //! just like in the introductory Derive example.
@@ -145,7 +149,7 @@
 //! #[test]
 //! fn test() {
 //!     assert_eq!(Number::Zero.discriminant(), 0);
-//!     assert_eq!(Number::First.value().description, "First position");
+//!     assert_eq!(Number::First.value_ref().description, "First position");
 //!     assert_eq!(Number::Second.clone(), Number::Second);
 //!     assert_eq!(Number::Third, Number::value_to_variant(
 //!         &NumberDescription { description: "Third position", index: 3 }));
@@ -184,6 +188,10 @@
 //! also before the enum, but it is required that the ##[features(...)] is the last of the enum's 
 //! declaration metadatas as this is not another metadata (hence the double octothorpe to denote
 //! it).
+//!
+//! The pairs of *Variant, Value* can also come from another macro, for example, one kept in a
+//! different file and included through ```include!(...)```, by writing ```variants from *your macro*```
+//! instead of the pairs, you can find how to write said macro in [create_indexed_valued_enum].
 //! <br>
 //!
 //! ## 2.a.3 Other examples for the declarative macro
@@ -253,7 +261,7 @@
 //! #[test]
 //! fn test() {
 //!     assert_eq!(Number::Zero.discriminant(), 0);
-//!     assert_eq!(Number::First.value().description, "First position");
+//!     assert_eq!(Number::First.value_ref().description, "First position");
 //!     assert_eq!(Number::Second.clone(), Number::Second);
 //!     assert_eq!(Number::Third, Number::value_to_variant(
 //!         &NumberDescription { description: "Third position", index: 3 }));
@@ -319,7 +327,7 @@
 //! ```
 //! <br>
 //!
-//! **Variant's with fields can be added too!** Unlike the declarative macro, this one is compatible
+//! **Variant's with fields can be added too!** Like the declarative macro, this one is compatible
 //! with variants with fields, be them named or unnamed, but they have a downside: since the 
 //! [Indexed::from_discriminant] function must return a constant value for each variant, we also
 //! need to create those variants with values at compile, when this situation arises you have two 
@@ -398,8 +406,92 @@
 //!
 //! ## 3 Extra features
 //!
+//! * **TryFromValue**: Implements TryFrom of your type of value, converting a value into the
+//! variant having it like ```Number::try_from(2)```, failing with a NoVariantForValue error when no
+//! variant has such value, this requires the type of value to implement PartialEq, and it can't be
+//! combined with **TryFromDiscriminant** when your values are of type usize.<br><br>
+//! * **IntoValue**: Implements From<YourEnum> for your type of value, converting a variant into it's
+//! value like ```let value: u8 = variant.into();```, this requires the type of value to implement
+//! Copy, and it can't be combined with **IntoDiscriminant** when your values are of type usize.<br><br>
+//! * **StrReverseLookup**: Implements the const 'SORTED_STR_VALUES' and the functions
+//! 'value_to_variant_str' and 'value_to_variant_str_opt', which find the variant with a string
+//! value by binary search in O(log n), without phf nor hashing, this requires the derive macro as
+//! values are sorted while expanding it, and every value must be a unique string literal, failing
+//! to compile otherwise, note 'value_to_variant_str' panics when no variant has the value.<br><br>
 //! * **DerefToValue**: Implements Deref, dereferencing each variant to a static reference of their
 //! value.<br><br>
+//! * **AsRefValue**: Implements AsRef, giving each variant as a static reference of their value,
+//! which allows to pass variants to functions taking an AsRef of your type of value, and unlike
+//! **DerefToValue**, the conversion is always explicit.<br><br>
+//! * **BorrowValue**: Implements Borrow, borrowing each variant as a static reference of their value,
+//! allowing to look up a map keyed by your enum using values, like ```map.get(&value)```, note this
+//! requires your enum's Hash, Eq and Ord to behave the same as the ones of your values, meaning
+//! values must be unique and hashing must be done through the **HashByValue** feature, and for
+//! ordered collections like BTreeMap, ordering must be done through the
+//! **OrdByValueThenDiscriminant** feature or values must be sorted, as otherwise variants aren't
+//! ordered like their values.<br><br>
+//! * **HashByValue**: Implements Hash, hashing each variant as their value.<br><br>
+//! * **OrdByValueThenDiscriminant**: Implements Ord and PartialOrd, comparing variants by their
+//! value and then by their discriminant, giving a stable order even when values are repeated, this
+//! requires the type of value to implement Ord, and your enum to implement Eq.<br><br>
+//! * **OrdByDiscriminant**: Implements Ord and PartialOrd, comparing variants only by their
+//! discriminant, this is, in their order of declaration, without requiring their fields to implement
+//! anything, this requires your enum to implement Eq, and as a derived Eq compares fields, use
+//! **BTreeKey** instead when variants with fields must also be equal regardless of them.<br><br>
+//! * **HashByDiscriminant**: Implements Hash, hashing each variant only as it's discriminant, so
+//! variants with fields hash cheaply without requiring their fields to implement Hash, note this
+//! intentionally makes variants that only differ in the contents of their fields collide.<br><br>
+//! * **BTreeKey**: Implements PartialEq, Eq, PartialOrd, Ord and Hash, all of them comparing or
+//! hashing variants only by their discriminant and ignoring their fields, this makes any enum, even
+//! one with fields, a cheap key for BTreeMap and HashMap, as these implementations are always
+//! consistent between each other, use this feature rather than deriving or implementing these traits
+//! separately.<br><br>
+//! * **DebugNameAndValue**: Implements Debug, formatting each variant as it's name followed by it's
+//! value, like ```Mars(3.71)```, this requires the type of value to implement Debug, and the fields
+//! of variants with fields aren't formatted.<br><br>
+//! * **TryFromDiscriminant**: Implements TryFrom<usize>, converting a discriminant into it's variant
+//! like ```Planet::try_from(1)```, failing with a DiscriminantOutOfRange error when there is no variant
+//! with such discriminant, which allows to use '?' when reading discriminants from external data.<br><br>
+//! * **NumFromPrimitive** and **NumToPrimitive**: Implement the traits FromPrimitive and ToPrimitive
+//! of num-traits, converting numbers into the variant with such discriminant, like
+//! ```Number::from_u64(2)```, and variants into their discriminant, like ```variant.to_u64()```, just
+//! like with serde, these are implemented over **your** dependency on num-traits.<br><br>
+//! * **IntoDiscriminant**: Implements From<YourEnum> and From<&YourEnum> for usize, converting a
+//! variant into it's discriminant like ```let discriminant: usize = variant.into();```, being the
+//! counterpart of **TryFromDiscriminant**, converting by value doesn't require your enum to implement
+//! Clone.<br><br>
+//! * **Registry**: Implements the const 'REGISTRY', pairing the name of each variant with it's
+//! discriminant, like ```[("Earth", 0), ("Mars", 1)]```, which can be directly looped over to
+//! register every variant by it's name, such as in plugin systems or dispatch tables.<br><br>
+//! * **Names**: Implements the const 'VARIANT_NAMES' and the const function 'variant_name', giving the
+//! names of your variants without importing the Named trait, just like strum does, where raw
+//! identifiers like r#type are named without 'r#'.<br><br>
+//! * **Display**: Implements Display, formatting each variant as it's name, like ```Mars```, where
+//! raw identifiers like r#type are formatted without 'r#', this doesn't require the type of value to
+//! implement anything, note this can't be combined with other features implementing Display.<br><br>
+//! * **FromStr**: Implements FromStr, parsing the name of a variant as it's given by the feature
+//! **Display**, like ```"Mars".parse()```, failing with a ParseVariantError when the name doesn't
+//! belong to any variant, which doesn't keep the name so it can be used without allocating.<br><br>
+//! * **DisplayNameValue**: Implements Display, formatting each variant as it's name and value, like
+//! ```Mars = 3.71```, which is ready to be logged as a key-value line, this requires the type of value
+//! to implement Display, note this can't be combined with other features implementing Display.<br><br>
+//! * **Bitset**: Implements the functions 'to_bitset' and 'from_bitset', which pack a slice of
+//! variants into an u64 where each bit is the presence of the variant whose discriminant is the bit's
+//! position, and unpack it back into a VariantSet, this requires the enum to have at most 64 variants
+//! and none of them having fields, and when using serde, a collection of variants can be de/serialized
+//! as this bitset through ```#[serde(with = "indexed_valued_enums::serde_compatibility::bitset")]```.<br><br>
+//! * **Default**: Implements Default giving the variant marked with #[default] when using the derive
+//! macro, or the variant with the lowest discriminant when no variant is marked or when using the
+//! declarative macro, along with the contents of its fields used in VARIANTS, marking more than one
+//! variant with #[default] fails to compile, and just like **DefaultFirst**, it can't be combined with
+//! #[derive(Default)] or any other implementation of Default.<br><br>
+//! * **DefaultFirst**: Implements Default giving the variant with the lowest discriminant, along
+//! with the contents of its fields used in VARIANTS, failing to compile if the enum doesn't have any
+//! variant, it can't be combined with #[derive(Default)] and #[default] or any other implementation of
+//! Default, as they would conflict.<br><br>
+//! * **SumValues** and **ProductValues**: Implement the functions 'sum_of_all_values' and
+//! 'product_of_all_values', which sum or multiply the values of every variant, these require the
+//! type of value to implement Copy along Sum or Product respectively.<br><br>
 //! * **Clone**: Implements clone calling 'from_discriminant', avoiding large expansions of the
 //! Derive Clone, this however won't clone the fields of your variants if there are some, being
 //! rather ideal in the case of large field-less enums.<br>Since it calls 'discriminant' and then
@@ -410,22 +502,122 @@
 //! **are const** functions.<br>
 //! Note it doesn't delegate the methods 'value_to_variant' and 'value_to_variant_opt' as they
 //! require the type of value to implement [PartialEq], you can delegate these too with the feature
-//! **ValueToVariantDelegators**, but these delegator functions are **not const**.<br><br>
+//! **ValueToVariantDelegators**, but these delegator functions are **not const**.<br>
+//! It also implements 'dispatch_value', which calls the function in the position of the variant's
+//! discriminant from an array of VARIANT_COUNT handlers, dispatching without a match.<br>
+//! When values are large or don't implement Copy, you can use **DelegatorsValueByRef** instead
+//! (or add the attribute #[enum_value_by_ref] when using the derive macro), which makes 'value'
+//! return a static reference to the value, and gives copies through 'value_owned' only when
+//! the value implements Copy.<br>
+//! If these functions collide with your own, you can rename them with the attribute
+//! #[delegator_names(value = "val", discriminant = "idx")] when using the derive macro, or by
+//! using the feature **(Delegators value: val, discriminant: idx)** when using the declarative
+//! macro, listing just the functions to rename, this includes 'neighbors', 'dispatch_value' and
+//! 'label'.<br><br>
+//! * **ValueIndex**: Implements the functions 'value_to_variant_indexed' and
+//! 'value_to_variant_indexed_opt', which find the variant corresponding to a value in O(1) through a
+//! static map built at compile time with phf, this is meant for large field-less enums whose reverse
+//! lookups are on a hot path.<br>
+//! This requires the feature phf of this crate, like
+//! ``` indexed_valued_enums = { version = "1.0.0", features=["phf"] } ```, and phf must be a
+//! dependency on your Cargo.toml with it's feature macros, the type of value must implement Hash and
+//! Eq, values must be unique literals supported by phf, like strings, chars or integers written with
+//! their type's suffix, like ```200u16```.<br><br>
+//! * **SequenceCompat**: Implements the trait Sequence of the enum-iterator crate through the
+//! discriminants of the variants, letting your enum be used by code built around enum-iterator.<br>
+//! This requires the feature enum_iterator_compat of this crate, like
+//! ``` indexed_valued_enums = { version = "1.0.0", features=["enum_iterator_compat"] } ```, and
+//! enum-iterator must be a dependency on your Cargo.toml.<br><br>
+//! * **ConstTraitImpls**: Implements the traits ConstIndexed and ConstValued as `impl const`, so
+//! functions like 'discriminant', 'from_discriminant' or 'value' can be called through traits in
+//! const contexts, including generic const functions.<br>
+//! This requires a nightly compiler and the feature const_trait of this crate, like
+//! ``` indexed_valued_enums = { version = "1.0.0", features=["const_trait"] } ```, and your crate
+//! must enable the nightly feature with #![feature(const_trait_impl)].<br><br>
 //! * **ValueToVariantDelegators**: Implements delegator functions calling to
 //! [Valued::value_to_variant] and [Valued::value_to_variant_opt].<br><br>
+//! * **IterValues**: Implements the function 'iter_values', which iterates over references to the
+//! values of every variant in order of discriminant without needing to import the Valued trait.<br><br>
+//! * **SortedValues**: Implements the functions 'value_to_variant_sorted' and
+//! 'value_to_variant_sorted_opt', which find the variant corresponding to a value through a binary
+//! search, being O(log n) rather than O(n), this requires the values to be strictly increasing, and
+//! it's checked at compile time, meaning values must be of a type that can be compared in const
+//! contexts, like integers or chars (for other types, see **SortedValuesUnchecked**), for example,
+//! this fails to compile:
+//! ```rust compile_fail
+//! use indexed_valued_enums::create_indexed_valued_enum;
+//!
+//! create_indexed_valued_enum! {
+//!     ##[features(SortedValues)]
+//!     enum Unsorted valued as u8;
+//!     First, 10,
+//!     Second, 5
+//! }
+//! ```
+//! <br>
+//! * **SortedValuesUnchecked**: Implements the same functions as **SortedValues** without checking
+//! the values are sorted at compile time, which allows values that can't be compared in const
+//! contexts, like strings or your own types implementing Ord, in which case keeping them strictly
+//! increasing is up to you, as otherwise these functions may not find some variants.<br><br>
 //! * De/Serialization features: These allow to serialize and deserialize this enum as just it's
 //! discriminant value, this is useful when your enum consists on variants without fields.
 //! <br><br>
 //! The features **Serialize** and **Deserialize** match the Serialize and DeserializeOwned traits,
 //! of serde, to use this, you must add the feature serde_enums on Cargo.toml, like:
 //! ``` indexed_valued_enums = { version = "1.0.0", features=["serde_enums"] } ``` <br><br>
-//! The features **NanoSerBin**, **NanoDeBin**, **NanoSerJson** and **NanoDeJson** implements the
-//! nanoserde's traits SerBin, DeBin, SerJson and DeJson respectively.<br><br>
+//! The feature **CompactDiscriminant** implements both Serialize and Deserialize using the
+//! discriminant, like **Serialize** and **Deserialize**, but written as the smallest of u8, u16 or u32
+//! fitting every discriminant of your enum rather than as an u128, shrinking binary payloads.<br><br>
+//! To pick the width of the discriminant yourself, write ##[serialize_discriminant_as(u8)] after the
+//! features of the declarative macro, or #[serialize_discriminant_as(u8)] when using the derive macro,
+//! which implements both Serialize and Deserialize writing the discriminant as that type, and fails to
+//! compile once your enum has more variants than that type can tell apart, like a 257th variant for
+//! u8, rather than truncating discriminants when serializing, **CompactDiscriminant**,
+//! **NanoCompactDiscriminant**, **SerializeUnitVariant** and **BincodeEncode** are guarded in the
+//! same way.<br><br>
+//! The feature **DeserializeLenient** works like **Deserialize**, but it also accepts the
+//! discriminant wrapped in a single-element array (like `[1]`) as some encoders do, note this
+//! requires a self-describing format, such as JSON.<br><br>
+//! The features **SerializeValue** and **DeserializeValue** serialize and deserialize the variant
+//! as it's value rather than it's discriminant, so consumers without your enum can still read the
+//! value, these require the type of value to implement Serialize, or Deserialize and PartialEq
+//! respectively, and deserializing fails if no variant has the deserialized value.<br><br>
+//! The features **SerializeNamed** and **DeserializeNamed** serialize and deserialize the variant as
+//! it's name rather than it's discriminant, like ```"First"```, which is readable and doesn't break
+//! when reordering variants.<br><br>
+//! The feature **SerializeUnitVariant** serializes the variant as serde's unit variant of your
+//! enum, just like serde's derive does for field-less enums, so self-describing formats know it's an
+//! enum and write it as their own enum representation, like ```"First"``` in JSON.<br><br>
+//! The feature **SerializeExternallyTagged** implements both Serialize and Deserialize, writing the
+//! variant as a map whose single key is it's name and whose value is a unit, like ```{"First":null}```
+//! in JSON, which is how externally tagged schemas expect enums, and deserializing fails on missing,
+//! unknown or extra keys.<br><br>
+//! The feature **DeserializeByNameCaseInsensitive** deserializes the variant from it's name rather
+//! than it's discriminant, ignoring ASCII case differences, so "mars", "Mars" and "MARS" are all
+//! deserialized as Mars, this fails to compile if two variants have the same name when ignoring
+//! case.<br><br>
+//! The feature **DeserializeFlexible** deserializes the variant from either it's discriminant or
+//! it's name, like ```1``` or ```"First"```, which is useful when migrating a format from
+//! discriminants to names, note this requires a self-describing format, such as JSON.<br><br>
+//! The feature **SerdeHumanAware** implements both Serialize and Deserialize, using the variant's
+//! name on human readable formats (like JSON or YAML) and it's discriminant on binary formats (like
+//! bincode or postcard), keeping binary payloads small and human readable ones legible.<br><br>
+//! The features **NanoSerBin**, **NanoDeBin**, **NanoSerJson**, **NanoDeJson**, **NanoSerRon** and
+//! **NanoDeRon** implements the nanoserde's traits SerBin, DeBin, SerJson, DeJson, SerRon and DeRon
+//! respectively.<br><br>
+//! The feature **NanoDeBin** also implements the function 'de_bin_vec', which reads a whole
+//! sequence of variants written by nanoserde as a Vec, validating each discriminant.<br><br>
+//! The feature **NanoCompactDiscriminant** implements nanoserde's SerBin and DeBin like
+//! **CompactDiscriminant** does for serde, writing the discriminant as the smallest of u8, u16 or u32
+//! fitting every discriminant of your enum rather than as an usize.<br><br>
+//! The features **BincodeEncode** and **BincodeDecode** implement the traits Encode and Decode of
+//! bincode 2, encoding the variant as it's discriminant in an u32, note BincodeDecode requires
+//! bincode's 'alloc' feature, which is enabled by default.<br><br>
 //! **IMPORTANT**: When using these De/Serialization, it will try to implement them over **your**
 //! dependencies, this means indexed_valued_enums won't directly depend on Serde or NanoSerde when
 //! implementing these interfaces, so if you want to use the De/Serialization methods of
 //! nanoserde, then nanoserde must be a dependency on your Cargo.toml, thanks to this, you always
-//! have control over which version of Serde and NanoSerde is being applied.
+//! have control over which version of Serde, NanoSerde and bincode is being applied.
 //!
 //!
 //! ## 4 Assumptions this crate does
@@ -434,17 +626,47 @@
 //! [extra features](#3-extra-features), this is because when expanding macros, it will try to
 //! target **your** dependencies, by doing this, you avoid longer compile times when this crate and
 //! yours use different versions, the dependencies you might need would be: ```serde```,
-//! ```nanoserde```, and ```const-default```.<br><br>
+//! ```nanoserde```, ```phf```, ```num-traits``` and ```const-default```.<br><br>
 //! * The variants of your enum don't have their discriminant manually set-up, this is because
 //! values to these variants are stored in an array, where each value is stored in the index
 //! corresponding to their variant's position and therefore discriminant, meaning the discriminant
 //! as an index.<br><br>
+//! * The variants written in the declarative macros aren't attributed with #[cfg(...)], as their
+//! values are stored by their position, so removing a variant would give the ones after it the values
+//! of others, this is rejected with a compile error, while other attributes like doc comments or
+//! #[deprecated] are kept on the variant.<br><br>
 //! * The enums are attributed with #[repr(usize)], you don't need to do this manually, the
 //! declarative macro does it by itself, and when using the attribute
 //! '#[enum_valued_as(*Your type*)]' it silently adds #[repr(usize)], but if you were to use cargo
-//! expand and use the original code, the #[repr(usize)] attribute must remain.<br><br>
+//! expand and use the original code, the #[repr(usize)] attribute must remain.<br>
+//! To make your enum smaller, you can pick a different type for its discriminant with
+//! ##[discriminant_repr(u8)] after the features of the declarative macro, or
+//! #[discriminant_repr(u8)] when using the derive macro, only u8, u16, u32, u64 and usize are
+//! accepted.<br><br>
+//!
+//!
+//! ## 5 Breaking changes
+//!
+//! * Getting a value as a copy through Valued::value, Valued::value_opt, value_internal and
+//! value_opt_internal now requires the type of value to implement Copy, as these used to give away
+//! a bitwise copy of the value, leaving two owners of it, which is unsound when the value owns
+//! resources or implements Drop.<br>
+//! If your values don't implement Copy, use Valued::value_ref to get a reference to the value
+//! instead, or Valued::value_cloned to get a clone of it when it implements Clone.<br><br>
+//! * The features **Delegators** and **DelegatorsValueByRef** now also generate the functions
+//! 'eq_discriminant', 'first', 'last', 'neighbors', 'dispatch_value' and 'label', so enums already
+//! defining functions with any of these names fail to compile as they are defined twice, to fix this,
+//! rename the generated functions, like #[delegator_names(first = "first_variant")] when using the
+//! derive macro, or ##[features((Delegators first: first_variant))] when using the declarative
+//! macro.<br><br>
 
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "derive")]
 extern crate indexed_valued_enums_derive;
 
@@ -461,6 +683,30 @@ pub mod valued_enum;
 /// Defines a trait index enums over an array using it's discriminant
 pub mod indexed_enum;
 
+/// Defines a trait to get the names of an enum's variants
+pub mod named_enum;
+
+/// Allows to temporarily override the values of an enum in the current thread, this requires
+/// the feature 'value_override'
+#[cfg(feature = "value_override")]
+pub mod value_override;
+
+/// Defines const versions of the traits [Indexed] and [Valued], this requires the feature
+/// 'const_trait' and a nightly compiler
+#[cfg(feature = "const_trait")]
+pub mod const_traits;
+
+/// Defines a table associating a value to every variant of an enum, for values that can't be const
+pub mod map;
+
+/// Defines a table associating a value to every variant of an enum stored on the heap, this
+/// requires the feature 'alloc' or 'std'
+#[cfg(feature = "alloc")]
+pub mod indexed_vec;
+
+/// Defines a set of variants of an enum stored as a bitmask
+pub mod set;
+
 /// Defines the main macro used to expand a list of values to an enum where each variant has an
 /// associated values
 pub mod macros;
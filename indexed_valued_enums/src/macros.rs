@@ -15,6 +15,8 @@ use crate::valued_enum::Valued;
 /// create_indexed_valued_enum!{ <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;	**Your metadata** //Like '#[derive(...)]', this is optional <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;	**##**[features(**Feature1**, **Feature2**, ...)] // this is optional, but it needs **two** octothorpes<br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;**##**[discriminant_repr(**u8**)] // this is optional, see *DiscriminantRepr* below<br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;**##**[serialize_discriminant_as(**u8**)] // this is optional, see *SerializeDiscriminantAs* below<br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;	**Visibility** enum **Enum's name** values as **TypeOfValue**; <br><br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;	***Variant1's metadata*** //this is optional<br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;	***Variant1***, ***Value1***,<br><br>
@@ -37,10 +39,46 @@ use crate::valued_enum::Valued;
 /// * *Features*: List of specific implementations you want your enum to use, see the section
 /// * *Features*: List of specific implementations you want your enum to use, you can find a list of
 ///               them in the documentation of [crate] -> Section: Extra features.
+/// * *DiscriminantRepr*: Unsigned integer type used for the enum's discriminant in memory instead
+///   of usize, like u8 or u16, making the enum smaller, while [Indexed::discriminant] and the rest of
+///   functions still work with discriminants as usize.
+/// * *SerializeDiscriminantAs*: Unsigned integer type used to serialize and deserialize the enum's
+///   discriminant with serde, like u8 or u16, failing to compile if the enum has more variants than
+///   this type can tell apart, rather than truncating their discriminants when serializing.
+///
+/// Variants can also have fields, in which case the declaration of the fields is followed by the
+/// contents these fields have in [Indexed::VARIANTS], like *Variant*(u8, u16) = (2, 3), *Value* or
+/// *Variant* { x: i32, y: i32 } = { x: 1, y: 2 }, *Value*, these contents must be const.
 ///
 /// Note: You can write metadata (Such as #[derive(...)]) before each pair of *Variant, Value*, and
 /// also before the enum, but it is required that the ##[features(...)] is the last of the
-/// metadatas as this is not another metadata (hence the double hashtag to denote it)///
+/// metadatas as this is not another metadata (hence the double hashtag to denote it)
+///
+/// The pairs of *Variant, Value* can also be written by another macro, which is useful when these
+/// are generated or kept in a different file to be included through [include!], to do so, write
+/// 'variants from *your macro*' instead of the pairs, your macro will then receive the declaration
+/// of the enum, and it must call this macro with said declaration followed by the pairs, like:
+///
+/// ```rust
+/// use indexed_valued_enums::create_indexed_valued_enum;
+///
+/// //This macro could be written in a different file and then included with include!("...")
+/// macro_rules! number_variants {
+///     ($($enum_declaration:tt)*) => {
+///         indexed_valued_enums::create_indexed_valued_enum! {
+///             $($enum_declaration)*
+///             Zero, "Zero position",
+///             First, "First position"
+///         }
+///     };
+/// }
+///
+/// create_indexed_valued_enum! {
+///     pub enum MyOtherNumber valued as &'static str;
+///     variants from number_variants
+/// }
+/// ```
+///
 /// A simple example would look like:
 ///
 /// ```rust
@@ -82,28 +120,89 @@ macro_rules! create_indexed_valued_enum {
     (
         $(#[$metadata:meta])*
         $(##[features($($features:tt),*)])?
+        $(##[discriminant_repr($repr:ident)])?
+        $(##[serialize_discriminant_as($width:ident)])?
+        $visibility:vis enum $enum_name:ident valued as $value_type:ty;
+        variants from $variants_macro:ident $(;)?
+    ) => {
+        $variants_macro !{
+            $(#[$metadata])*
+            $(##[features($($features),*)])?
+            $(##[discriminant_repr($repr)])?
+            $(##[serialize_discriminant_as($width)])?
+            $visibility enum $enum_name valued as $value_type;
+        }
+    };
+    (
+        $(#[$metadata:meta])*
+        $(##[features($($features:tt),*)])?
+        $(##[discriminant_repr($repr:ident)])?
+        $(##[serialize_discriminant_as($width:ident)])?
         $visibility:vis enum $enum_name:ident valued as $value_type:ty;
-        $($(#[$variants_metadata:meta])* $variants:ident, $values:expr
-            $(;unnamed_field_initializers $($unnamed_field_initializers:expr),+)?
-            $(;named_field_initializers $($named_field_name:ident $named_field_value:expr),+)?
+        $($(#[$($variants_metadata:tt)*])* $variants:ident
+            $(($($unnamed_field_types:ty),+) = ($($unnamed_field_initializers:expr),+))?
+            $({$($named_field_declarations:ident : $named_field_types:ty),+} = {$($named_field_name:ident : $named_field_value:expr),+})?
+            , $values:expr
         ),+ $(,)?
     ) => {
-        $(#[$metadata])*
-        #[repr(usize)]
-        $visibility enum $enum_name{
-            $($(#[$variants_metadata:meta])* $variants),+,
+        $(indexed_valued_enums::create_indexed_valued_enum !{check discriminant repr $enum_name; $repr})?
+        $(indexed_valued_enums::create_indexed_valued_enum !{check variant attributes $variants;
+            $([$($variants_metadata)*])*})+
+
+        indexed_valued_enums::create_indexed_valued_enum !{enum with repr [$($repr)?]; $(#[$metadata])*
+            $visibility $enum_name {
+                $($(#[$($variants_metadata)*])* $variants
+                    $(($($unnamed_field_types),+))?
+                    $({$($named_field_declarations : $named_field_types),+})?
+                ),+,
+            }
         }
 
-        indexed_valued_enums::create_indexed_valued_enum !(impl traits $enum_name $value_type; $($variants, $values),+);
+        indexed_valued_enums::create_indexed_valued_enum !(impl traits $enum_name $value_type;
+            $([discriminant repr $repr];)? $($variants, $values
+            $(;unnamed_field_initializers $($unnamed_field_initializers),+ ;)?
+            $(;named_field_initializers $($named_field_name $named_field_value),+ ;)?
+        ),+);
 
         $(indexed_valued_enums::create_indexed_valued_enum !{process features $enum_name, $value_type; $($features);* })?
+
+        $(indexed_valued_enums::create_indexed_valued_enum !{serialize discriminant as $enum_name; $width})?
+
+        indexed_valued_enums::create_indexed_valued_enum !{value index features $enum_name $value_type;
+            [$($($features)*)?]; $($variants, $values),+}
+    };
+    (enum with repr []; $(#[$metadata:meta])* $visibility:vis $enum_name:ident { $($variants:tt)* }) => {
+        $(#[$metadata])*
+        #[repr(usize)]
+        $visibility enum $enum_name{ $($variants)* }
+    };
+    (enum with repr [$repr:ident]; $(#[$metadata:meta])* $visibility:vis $enum_name:ident { $($variants:tt)* }) => {
+        $(#[$metadata])*
+        #[repr($repr)]
+        $visibility enum $enum_name{ $($variants)* }
     };
+    (value index features $enum_name:ident $value_type:ty; [ValueIndex $($other_features:tt)*];
+        $($variants:ident, $values:expr),+)
+    =>{
+        indexed_valued_enums::create_value_index !{$enum_name $value_type; $($variants, $values),+}
+    };
+    (value index features $enum_name:ident $value_type:ty; [$feature:tt $($other_features:tt)*];
+        $($variants_and_values:tt)*)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{value index features $enum_name $value_type;
+            [$($other_features)*]; $($variants_and_values)*}
+    };
+    (value index features $enum_name:ident $value_type:ty; []; $($variants_and_values:tt)*) => {};
     (
-        impl traits $enum_name:ident $value_type:ty; $($variants:ident, $values:expr
+        impl traits $enum_name:ident $value_type:ty;
+        $([safe discriminant $($safe_variants:ident => $safe_discriminants:literal),+];)?
+        $([discriminant repr $repr:ty];)?
+        $($variants:ident, $values:expr
             $(;unnamed_field_initializers $($unnamed_field_initializers:expr),+ ;)?
             $(;named_field_initializers $($named_field_name:ident $(:)? $named_field_value:expr),+ ;)?
         ),+
     )=>{
+        #[allow(deprecated)]
         impl indexed_valued_enums::indexed_enum::Indexed for $enum_name {
             #[doc = concat!("Array storing all the variants of the [",stringify!($enum_name),"]\
             enum where each variant is stored in ordered by their discriminant")]
@@ -112,6 +211,27 @@ macro_rules! create_indexed_valued_enum {
             $({ $($named_field_name: $named_field_value), +})?
 
             ),+];
+
+            #[doc = concat!("Whether none of the variants of the [",stringify!($enum_name),"] \
+            enum have fields")]
+            const IS_FIELD_LESS: bool = 0 $($(+ [$(stringify!($unnamed_field_initializers)),+].len())?
+                $(+ [$(stringify!($named_field_name)),+].len())?)+ == 0;
+
+            $(
+            #[doc = concat!("Size of the discriminant of the [",stringify!($enum_name),"] enum, as \
+            it's attributed with #[repr(",stringify!($repr),")]")]
+            const DISCRIMINANT_SIZE: usize = core::mem::size_of::<$repr>();
+            )?
+
+            $(
+            #[doc = concat!("Gets the discriminant of this [",stringify!($enum_name),"]'s variant \
+            by matching it, rather than reading it from the enum's memory, this operation is O(1)")]
+            fn discriminant(&self) -> usize {
+                match self {
+                    $(Self::$safe_variants { .. } => $safe_discriminants),+
+                }
+            }
+            )?
         }
 
         impl indexed_valued_enums::valued_enum::Valued for $enum_name {
@@ -121,77 +241,378 @@ macro_rules! create_indexed_valued_enum {
              [",stringify!($enum_name),"] enum, each value is stored in the same order as the \
             discriminant of the variant they belong to")]
             const VALUES: &'static [ Self::Value] = & [$($values),+];
+
+            #[doc = concat!("Gives the value corresponding to this [",stringify!($enum_name),"]'s \
+            variant as a copy from [indexed_valued_enums::valued_enum::Valued::VALUES], naming the \
+            variant if it doesn't have a value")]
+            fn value(&self) -> Self::Value where for<'a> Self::Value: Copy {
+                match indexed_valued_enums::valued_enum::Valued::value_opt(self) {
+                    Some(value) => value,
+                    None => indexed_valued_enums::valued_enum::panic_missing_named_value::<Self>(
+                        indexed_valued_enums::indexed_enum::Indexed::discriminant(self)),
+                }
+            }
+
+            #[doc = concat!("Gives a reference to the value corresponding to this \
+            [",stringify!($enum_name),"]'s variant from \
+            [indexed_valued_enums::valued_enum::Valued::VALUES], naming the variant if it doesn't \
+            have a value")]
+            fn value_ref(&self) -> &'static Self::Value {
+                match indexed_valued_enums::valued_enum::Valued::value_ref_opt(self) {
+                    Some(value) => value,
+                    None => indexed_valued_enums::valued_enum::panic_missing_named_value::<Self>(
+                        indexed_valued_enums::indexed_enum::Indexed::discriminant(self)),
+                }
+            }
+        }
+
+        impl indexed_valued_enums::named_enum::Named for $enum_name {
+            #[doc = concat!("Array storing the names of all the variants of the \
+             [",stringify!($enum_name),"] enum, each name is stored in the same order as the \
+            discriminant of the variant they belong to")]
+            const VARIANT_NAMES: &'static [&'static str] = &[$(indexed_valued_enums::named_enum::unraw_identifier(stringify!($variants))),+];
+        }
+    };
+    (variant groups $enum_name:ident; $($groups:expr),+)=>{
+        impl $enum_name {
+            #[doc = concat!("Group of each [",stringify!($enum_name),"]'s variant, each group is \
+            stored in the same order as the discriminant of the variant they belong to")]
+            pub const GROUPS: &'static [&'static str] = &[$($groups),+];
+
+            #[doc = concat!("Gives the group this [",stringify!($enum_name),"]'s variant belongs to, \
+            this operation is O(1)")]
+            pub const fn group(&self) -> &'static str {
+                Self::GROUPS[indexed_valued_enums::indexed_enum::discriminant_internal_sized(self)]
+            }
+
+            #[doc = concat!("Iterates over every [",stringify!($enum_name),"]'s variant belonging to \
+            this group in order of discriminant")]
+            pub fn variants_in_group(group: &str) -> impl Iterator<Item = Self> + '_ {
+                <Self as indexed_valued_enums::indexed_enum::Indexed>::iter()
+                    .filter(move |variant| variant.group() == group)
+            }
+        }
+    };
+    (variants with value $enum_name:ident $value_type:ty; $($values:literal),+)=>{
+        impl $enum_name {
+            #[doc = concat!("Gives how many [",stringify!($enum_name),"]'s variants have this \
+            value, as a const function, this operation is O(n)")]
+            pub const fn variants_with_value(value: $value_type) -> usize {
+                0 $(+ matches!(value, $values) as usize)+
+            }
+        }
+    };
+    (value const eq $enum_name:ident $value_type:ty; $const_eq:path)=>{
+        impl $enum_name {
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant corresponding to \
+            this value as a const function, comparing values through [",stringify!($const_eq),"], \
+            this is an O(n) operation as it compares every value of [indexed_valued_enums::valued_enum::Valued::VALUES]")]
+            pub const fn value_to_variant_const(value: &$value_type) -> Option<Self> {
+                let values = <Self as indexed_valued_enums::valued_enum::Valued>::VALUES;
+                let mut discriminant = 0;
+                while discriminant < values.len() {
+                    if $const_eq(&values[discriminant], value) {
+                        return indexed_valued_enums::indexed_enum::from_discriminant_opt_internal(discriminant);
+                    }
+                    discriminant += 1;
+                }
+                None
+            }
+        }
+    };
+    (discriminant id $enum_name:ident; $id_type:ty)=>{
+        impl $enum_name {
+            #[doc = concat!("Gives this [",stringify!($enum_name),"]'s discriminant as a [",
+            stringify!($id_type),"], this is an O(1) operation")]
+            pub fn to_id(&self) -> $id_type {
+                <$id_type as From<usize>>::from(<Self as indexed_valued_enums::indexed_enum::Indexed>::discriminant(self))
+            }
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant whose discriminant is \
+            this [",stringify!($id_type),"], giving [None] if it can't be converted into a \
+            discriminant or there is no variant with such discriminant, this is an O(1) operation")]
+            pub fn from_id(id: $id_type) -> Option<Self> {
+                let discriminant: usize = core::convert::TryInto::try_into(id).ok()?;
+                <Self as indexed_valued_enums::indexed_enum::Indexed>::from_discriminant_opt(discriminant)
+            }
         }
     };
+    (check discriminant repr $enum_name:ident; $repr:ident)=>{
+        const _: usize = <$repr as indexed_valued_enums::indexed_enum::DiscriminantRepr>::SIZE;
+    };
+    //Variants are listed in VARIANTS and VALUES by their position, so a variant removed by #[cfg]
+    //would shift the discriminants of every variant after it
+    (check variant attributes $variant:ident; [cfg $($condition:tt)*] $($other_attributes:tt)*)=>{
+        compile_error!(concat!("The variant ", stringify!($variant), " can't be attributed with \
+        #[cfg(...)], as it would be removed from the enum but not from its variants and values"));
+    };
+    (check variant attributes $variant:ident; [$($attribute:tt)*] $($other_attributes:tt)*)=>{
+        indexed_valued_enums::create_indexed_valued_enum !{check variant attributes $variant;
+            $($other_attributes)*}
+    };
+    (check variant attributes $variant:ident;)=>{};
     (process features $enum_name:ident, $value_type:ty; $($features:tt);*)=>{
         $(indexed_valued_enums::create_indexed_valued_enum !{process feature $enum_name, $value_type; $features })*
     };
     (process feature $enum_name:ident, $value_type:ty; Delegators)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{process feature $enum_name, $value_type; (Delegators)}
+    };
+    (process feature $enum_name:ident, $value_type:ty; (Delegators $($function:ident: $new_name:ident),* $(,)?))
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{delegators $enum_name, $value_type; Delegators [
+            discriminant from_discriminant_opt from_discriminant eq_discriminant
+            first last value_opt value value_ref_opt value_ref neighbors dispatch_value label]
+            [$($function => $new_name),*]}
+    };
+    (process feature $enum_name:ident, $value_type:ty; DelegatorsValueByRef)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{process feature $enum_name, $value_type; (DelegatorsValueByRef)}
+    };
+    (process feature $enum_name:ident, $value_type:ty; (DelegatorsValueByRef $($function:ident: $new_name:ident),* $(,)?))
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{delegators $enum_name, $value_type; DelegatorsValueByRef [
+            discriminant from_discriminant_opt from_discriminant eq_discriminant
+            first last value_opt value value_owned_opt value_owned neighbors dispatch_value label]
+            [$($function => $new_name),*]}
+    };
+    // Generates each function of the feature through the delegator arm named after it, which looks
+    // its name up in the list of renames, keeping its default name when it isn't renamed
+    (delegators $enum_name:ident, $value_type:ty; $feature:ident [$($function:ident)*] $renames:tt)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{check delegator names $feature [$($function)*] $renames}
+        $(indexed_valued_enums::create_indexed_valued_enum !{delegator $enum_name, $value_type; $feature $function $renames})*
+    };
+    // Renaming a function the feature doesn't generate fails to find its variant in this enum
+    (check delegator names $feature:ident [$($function:ident)*] [$($renamed:ident => $new_name:ident),*])
+    =>{
+        const _: () = {
+            #[allow(non_camel_case_types, dead_code)]
+            enum $feature { $($function),* }
+            $(let _ = $feature::$renamed;)*
+        };
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident discriminant [discriminant => $discriminant:ident $(, $($renames:tt)*)?])
     =>{
         impl $enum_name {
             #[doc = concat!("Gets the discriminant of this",stringify!($enum_name),", this \
             operation is O(1)")]
-            pub const fn discriminant(&self) -> usize {
-                indexed_valued_enums::indexed_enum::discriminant_internal(self)
+            pub const fn $discriminant(&self) -> usize {
+                indexed_valued_enums::indexed_enum::discriminant_internal_sized(self)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident from_discriminant_opt [from_discriminant_opt => $from_discriminant_opt:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gets the",stringify!($enum_name),"'s variant corresponding to said \
             discriminant, this operation is O(1) as it just gets the discriminant as a copy from \
             [indexed_valued_enums::indexed_enum::Indexed::VARIANTS], meaning this enum does not \
             need to implement [Clone]")]
-            pub const fn from_discriminant_opt(discriminant: usize) -> Option<Self> {
+            pub const fn $from_discriminant_opt(discriminant: usize) -> Option<Self> {
                 indexed_valued_enums::indexed_enum::from_discriminant_opt_internal(discriminant)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident from_discriminant [from_discriminant => $from_discriminant:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gets the",stringify!($enum_name),"'s variant corresponding to said \
             discriminant, this operation is O(1) as it just gets the discriminant as a copy from \
             [indexed_valued_enums::indexed_enum::Indexed::VARIANTS], meaning this enum does not \
             need to implement [Clone]")]
-            pub const fn from_discriminant(discriminant: usize) -> Self {
+            pub const fn $from_discriminant(discriminant: usize) -> Self {
                 indexed_valued_enums::indexed_enum::from_discriminant_internal(discriminant)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident eq_discriminant [eq_discriminant => $eq_discriminant:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Checks whether both ",stringify!($enum_name),"'s variants have the same \
+            discriminant, this can be used in const contexts where [PartialEq] can't, note the \
+            fields of the variants aren't compared")]
+            pub const fn $eq_discriminant(first: &Self, second: &Self) -> bool {
+                indexed_valued_enums::indexed_enum::discriminant_internal_sized(first)
+                    == indexed_valued_enums::indexed_enum::discriminant_internal_sized(second)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident first [first => $first:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gets the ",stringify!($enum_name),"'s variant with the lowest \
+            discriminant, this operation is O(1) and always returns [Option::Some] unless the enum \
+            doesn't have any variant")]
+            pub const fn $first() -> Option<Self> {
+                indexed_valued_enums::indexed_enum::first_internal()
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident last [last => $last:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gets the ",stringify!($enum_name),"'s variant with the highest \
+            discriminant, this operation is O(1) and always returns [Option::Some] unless the enum \
+            doesn't have any variant")]
+            pub const fn $last() -> Option<Self> {
+                indexed_valued_enums::indexed_enum::last_internal()
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; Delegators value_opt [value_opt => $value_opt:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gives the value of type [",stringify!($value_type),"] corresponding \
             to this [", stringify!($enum_name),"] 's variant, this operation is O(1) as it just \
             gets the discriminant as a copy from \
             [indexed_valued_enums::valued_enum::Valued::VALUES] \
             If you just need a reference to the value, use \
-            [",stringify!($enum_name),"::value_ref])] instead, as it doesn't require a read copy)
+            [indexed_valued_enums::valued_enum::Valued::value_ref] instead, as it doesn't require a read copy
             <br><br>This always returns [Option::Some], so it's recommended to call\
-            [",stringify!($enum_name),"::value] instead")]
-            pub const fn value_opt(&self) -> Option<$value_type> {
+            [indexed_valued_enums::valued_enum::Valued::value] instead")]
+            pub const fn $value_opt(&self) -> Option<$value_type> where for<'a> $value_type: Copy {
                 indexed_valued_enums::valued_enum::value_opt_internal(self)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; Delegators value [value => $value:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gives the value of type [",stringify!($value_type),"] corresponding \
             to this [", stringify!($enum_name),"] 's variant, this operation is O(1) as it just \
             gets the discriminant as a copy from \
             [indexed_valued_enums::valued_enum::Valued::VALUES]. <br>\
             If you just need a reference to the value, use \
-            [",stringify!($enum_name),"::value_ref])] instead, as it doesn't require a read copy")]
-            pub const fn value(&self) -> $value_type {
+            [indexed_valued_enums::valued_enum::Valued::value_ref] instead, as it doesn't require a read copy")]
+            pub const fn $value(&self) -> $value_type where for<'a> $value_type: Copy {
                 indexed_valued_enums::valued_enum::value_internal(self)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; Delegators value_ref_opt [value_ref_opt => $value_ref_opt:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gives the value of type [",stringify!($value_type),"] corresponding \
             to this [", stringify!($enum_name),"] 's variant, if you need a copy of the value \
-            but the value doesn't implement Clone, use [",stringify!($enum_name),"::value_opt]\
+            but the value doesn't implement Clone, use [indexed_valued_enums::valued_enum::Valued::value_opt]\
             instead, as it performs a read copy \
             <br><br>This always returns [Option::Some], so it's recommended to call\
-            [",stringify!($enum_name),"::value] instead")]
-            pub const fn value_ref_opt(&self) -> Option<&'static $value_type> {
+            [indexed_valued_enums::valued_enum::Valued::value_ref] instead")]
+            pub const fn $value_ref_opt(&self) -> Option<&'static $value_type> {
                 indexed_valued_enums::valued_enum::value_ref_opt_internal(self)
             }
-
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; Delegators value_ref [value_ref => $value_ref:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
             #[doc = concat!("Gives the value of type [",stringify!($value_type),"] corresponding \
             to this [", stringify!($enum_name),"] 's variant, if you need a copy of the value\
-            but the value doesn't implement Clone, use [",stringify!($enum_name),"::value] \
+            but the value doesn't implement Clone, use [indexed_valued_enums::valued_enum::Valued::value] \
             instead as it performs a read copy")]
-            pub const fn value_ref(&self) -> &'static $value_type {
+            pub const fn $value_ref(&self) -> &'static $value_type {
+                indexed_valued_enums::valued_enum::value_ref_internal(self)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; DelegatorsValueByRef value_opt [value_opt => $value_opt:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives a reference to the value of type [",stringify!($value_type),"] \
+            corresponding to this [", stringify!($enum_name),"] 's variant, this operation is O(1) \
+            as it just gets a reference to the value from \
+            [indexed_valued_enums::valued_enum::Valued::VALUES] \
+            <br><br>This always returns [Option::Some], so it's recommended to call\
+            [indexed_valued_enums::valued_enum::Valued::value_ref] instead")]
+            pub const fn $value_opt(&self) -> Option<&'static $value_type> {
+                indexed_valued_enums::valued_enum::value_ref_opt_internal(self)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; DelegatorsValueByRef value [value => $value:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives a reference to the value of type [",stringify!($value_type),"] \
+            corresponding to this [", stringify!($enum_name),"] 's variant, this operation is O(1) \
+            as it just gets a reference to the value from \
+            [indexed_valued_enums::valued_enum::Valued::VALUES]. <br>\
+            If you need a copy of the value, use [indexed_valued_enums::valued_enum::Valued::value] \
+            instead")]
+            pub const fn $value(&self) -> &'static $value_type {
                 indexed_valued_enums::valued_enum::value_ref_internal(self)
             }
         }
     };
+    (delegator $enum_name:ident, $value_type:ty; DelegatorsValueByRef value_owned_opt [value_owned_opt => $value_owned_opt:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives a copy of the value of type [",stringify!($value_type),"] \
+            corresponding to this [", stringify!($enum_name),"] 's variant, this requires the \
+            value to implement [Copy] \
+            <br><br>This always returns [Option::Some], so it's recommended to call\
+            [indexed_valued_enums::valued_enum::Valued::value] instead")]
+            pub const fn $value_owned_opt(&self) -> Option<$value_type> where for<'a> $value_type: Copy {
+                match indexed_valued_enums::valued_enum::value_ref_opt_internal(self) {
+                    Some(value) => Some(*value),
+                    None => None,
+                }
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; DelegatorsValueByRef value_owned [value_owned => $value_owned:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives a copy of the value of type [",stringify!($value_type),"] \
+            corresponding to this [", stringify!($enum_name),"] 's variant, this requires the \
+            value to implement [Copy]")]
+            pub const fn $value_owned(&self) -> $value_type where for<'a> $value_type: Copy {
+                *indexed_valued_enums::valued_enum::value_ref_internal(self)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident neighbors [neighbors => $neighbors:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gets both the ",stringify!($enum_name),"'s variant preceding and the \
+            one following this variant as (previous, next), each being [None] when this is the \
+            first or last variant respectively, this operation is O(1)")]
+            pub const fn $neighbors(&self) -> (Option<Self>, Option<Self>) {
+                indexed_valued_enums::indexed_enum::neighbors_internal(self)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident dispatch_value [dispatch_value => $dispatch_value:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Calls the handler in the position of this ",stringify!($enum_name),"'s \
+            discriminant, giving back its result, this operation is O(1) as it indexes the \
+            handlers rather than matching the variant, where the handlers must be sorted in the \
+            same order as [indexed_valued_enums::indexed_enum::Indexed::VARIANTS]")]
+            pub fn $dispatch_value<R>(&self, handlers: &[fn() -> R; <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT]) -> R {
+                handlers[indexed_valued_enums::indexed_enum::discriminant_internal_sized(self)]()
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident label [label => $label:ident $(, $($renames:tt)*)?])
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives both the discriminant and the name of this ",stringify!($enum_name),"'s \
+            variant as (discriminant, name), which is useful to build logging or debugging tables \
+            in const contexts, this operation is O(1)")]
+            pub const fn $label(&self) -> (usize, &'static str) {
+                indexed_valued_enums::named_enum::label_internal(self)
+            }
+        }
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident $function:ident [$renamed:ident => $new_name:ident $(, $($renames:tt)*)?])
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{delegator $enum_name, $value_type; $feature $function [$($($renames)*)?]}
+    };
+    (delegator $enum_name:ident, $value_type:ty; $feature:ident $function:ident [])
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{delegator $enum_name, $value_type; $feature $function [$function => $function]}
+    };
     (process feature $enum_name:ident, $value_type:ty; ValueToVariantDelegators)
     =>{
         impl $enum_name {
@@ -210,6 +631,110 @@ macro_rules! create_indexed_valued_enum {
             }
         }
     };
+    (process feature $enum_name:ident, $value_type:ty; IterValues)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Iterates over references to the values of every [",stringify!($enum_name),"]'s \
+            variant in order of discriminant, taken from [indexed_valued_enums::valued_enum::Valued::VALUES]")]
+            pub fn iter_values() -> core::slice::Iter<'static, $value_type> {
+                <Self as indexed_valued_enums::valued_enum::Valued>::VALUES.iter()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SortedValues)
+    =>{
+        const _: () = {
+            let values = <$enum_name as indexed_valued_enums::valued_enum::Valued>::VALUES;
+            let mut index = 1;
+            while index < values.len() {
+                assert!(values[index - 1] < values[index], concat!("The feature SortedValues requires \
+                the values of ", stringify!($enum_name), " to be strictly increasing"));
+                index += 1;
+            }
+        };
+
+        indexed_valued_enums::create_indexed_valued_enum !{sorted values $enum_name, $value_type}
+    };
+    (process feature $enum_name:ident, $value_type:ty; SortedValuesUnchecked)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{sorted values $enum_name, $value_type}
+    };
+    (sorted values $enum_name:ident, $value_type:ty)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Gives [",stringify!($enum_name),"]'s variant corresponding to this \
+            value <br><br> this is an O(log n) operation as it does a binary search over \
+            [indexed_valued_enums::valued_enum::Valued::VALUES], which are sorted")]
+            pub fn value_to_variant_sorted_opt(value: &$value_type) -> Option<Self> where for<'a> $value_type: Ord {
+                let values = <Self as indexed_valued_enums::valued_enum::Valued>::VALUES;
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(values.binary_search(value).ok()?)
+            }
+
+            #[doc = concat!("Gives [",stringify!($enum_name),"]'s variant corresponding to this \
+            value <br><br> this is an O(log n) operation as it does a binary search over \
+            [indexed_valued_enums::valued_enum::Valued::VALUES], which are sorted")]
+            pub fn value_to_variant_sorted(value: &$value_type) -> Self where for<'a> $value_type: Ord {
+                Self::value_to_variant_sorted_opt(value).unwrap()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; ValueIndex)
+    =>{
+        // As features don't know the values of the variants, the index is built when expanding the
+        // variants through the 'value index features' arm
+    };
+    (process feature $enum_name:ident, $value_type:ty; StrReverseLookup)
+    =>{
+        compile_error!(concat!("The feature StrReverseLookup of enum ", stringify!($enum_name),
+            " can only be used with the derive macro, as it sorts the values while expanding it"));
+    };
+    (str reverse lookup $enum_name:ident; $($sorted_values:literal => $discriminants:literal),*)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Pairs of the value and discriminant of every [",stringify!($enum_name),"]'s \
+            variant, sorted by value so they can be searched by binary search")]
+            pub const SORTED_STR_VALUES: &'static [(&'static str, usize)] = &[$(($sorted_values, $discriminants)),*];
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant whose value is this \
+            string, this is an O(log n) operation as it's found by binary search over \
+            [",stringify!($enum_name),"::SORTED_STR_VALUES]")]
+            pub fn value_to_variant_str_opt(value: &str) -> Option<Self> {
+                let position = Self::SORTED_STR_VALUES.binary_search_by(|(sorted_value, _)| (*sorted_value).cmp(value)).ok()?;
+                indexed_valued_enums::indexed_enum::from_discriminant_opt_internal(Self::SORTED_STR_VALUES[position].1)
+            }
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant whose value is this \
+            string, this is an O(log n) operation as it's found by binary search over \
+            [",stringify!($enum_name),"::SORTED_STR_VALUES] <br><br>This panics if no variant has \
+            this value, if you aren't sure it exists, use \
+            [",stringify!($enum_name),"::value_to_variant_str_opt] instead")]
+            pub fn value_to_variant_str(value: &str) -> Self {
+                Self::value_to_variant_str_opt(value).unwrap()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; TryFromValue)
+    =>{
+        impl core::convert::TryFrom<$value_type> for $enum_name where $value_type: PartialEq {
+            type Error = indexed_valued_enums::valued_enum::NoVariantForValue;
+
+            #[doc = concat!("Gets the [",stringify!($enum_name),"]'s variant with this value, giving \
+            an error if no variant has such value, this is an O(n) operation")]
+            fn try_from(value: $value_type) -> Result<Self, Self::Error> {
+                <Self as indexed_valued_enums::valued_enum::Valued>::try_value_to_variant(&value)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; IntoValue)
+    =>{
+        impl core::convert::From<$enum_name> for $value_type where $value_type: Copy {
+            #[doc = concat!("Gives the value of this [",stringify!($enum_name),"]'s variant, this is \
+            an O(1) operation")]
+            fn from(variant: $enum_name) -> Self {
+                indexed_valued_enums::valued_enum::Valued::value(&variant)
+            }
+        }
+    };
     (process feature $enum_name:ident, $value_type:ty; DerefToValue)
     =>{
         impl core::ops::Deref for $enum_name{
@@ -220,84 +745,648 @@ macro_rules! create_indexed_valued_enum {
             [indexed_valued_enums::valued_enum::Valued::VALUES] is a constant array, the value will \
             be referenced for 'static")]
             fn deref(&self) -> &'static Self::Target {
-                &<Self as indexed_valued_enums::valued_enum::Valued>::VALUES[self.discriminant()]
+                &<Self as indexed_valued_enums::valued_enum::Valued>::VALUES[indexed_valued_enums::indexed_enum::Indexed::discriminant(self)]
             }
         }
     };
-    (process feature $enum_name:ident, $value_type:ty; Clone)
+    (process feature $enum_name:ident, $value_type:ty; AsRefValue)
     =>{
-        impl core::clone::Clone for $enum_name {
-
-            #[doc = concat!("Clones this [",stringify!($enum_name),"]'s variant<br><br>This clone \
-            is taken from the constant array of\
-            [indexed_valued_enums::indexed_enum::Indexed::VARIANTS], meaning this is a copy of that \
-            array, and therefore not causing a long macro expansion")]
-            fn clone(&self) -> Self {
-                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(self);
-                indexed_valued_enums::indexed_enum::Indexed::from_discriminant(discriminant)
+        impl core::convert::AsRef<$value_type> for $enum_name {
+            #[doc = concat!("Gives a reference to the value of type [",stringify!($value_type),"] \
+            corresponding to this [", stringify!($enum_name),"] 's variant, allowing to pass it to \
+            functions taking an AsRef<",stringify!($value_type),">")]
+            fn as_ref(&self) -> &$value_type {
+                indexed_valued_enums::valued_enum::Valued::value_ref(self)
             }
         }
     };
-    (process feature $enum_name:ident, $value_type:ty; Serialize)
+    (process feature $enum_name:ident, $value_type:ty; BorrowValue)
     =>{
-        impl serde::Serialize for $enum_name {
-            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
-            discriminant, reducing its serializing complexity")]
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-                serializer.serialize_u128(self.discriminant() as u128)
+        impl core::borrow::Borrow<$value_type> for $enum_name {
+            #[doc = concat!("Borrows the value of type [",stringify!($value_type),"] corresponding \
+            to this [", stringify!($enum_name),"] 's variant, allowing to look up collections keyed \
+            by [", stringify!($enum_name),"] using it's values<br><br>This requires the Hash, Eq and \
+            Ord implementations of [", stringify!($enum_name),"] to behave the same as those of \
+            it's values, Hash and Eq are achieved with the feature HashByValue and unique values, \
+            while Ord also requires the feature OrdByValueThenDiscriminant or sorted values, as \
+            otherwise ordered collections like BTreeMap won't find variants by their values")]
+            fn borrow(&self) -> &$value_type {
+                indexed_valued_enums::valued_enum::Valued::value_ref(self)
             }
         }
     };
-    (process feature $enum_name:ident, $value_type:ty; Deserialize)
+    (process feature $enum_name:ident, $value_type:ty; HashByValue)
     =>{
-        impl<'de> serde::Deserialize<'de> for $enum_name {
-            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
-            discriminant, reducing its deserializing complexity")]
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-                match deserializer.deserialize_u128(indexed_valued_enums::serde_compatibility::discriminant_visitor::DISCRIMINANT_VISITOR) {
-                    Ok(value) => {
-                        $enum_name::from_discriminant_opt(value).ok_or_else(|| serde::de::Error::custom(
-                            "Deserialized an discriminant that is bigger than the amount of variants",
-                        ))
-                    }
-                    Err(error) => { Err(error) }
-                }
+        impl core::hash::Hash for $enum_name {
+            #[doc = concat!("Hashes this [",stringify!($enum_name),"]'s variant as it's value, \
+            making it hash the same as it's value")]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(indexed_valued_enums::valued_enum::Valued::value_ref(self), state)
             }
         }
     };
-    (process feature $enum_name:ident, $value_type:ty; NanoSerBin)
+    (process feature $enum_name:ident, $value_type:ty; OrdByValueThenDiscriminant)
     =>{
-        impl nanoserde::SerBin for $enum_name {
-            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
-            discriminant, reducing its serializing complexity")]
-            fn ser_bin(&self, output: &mut Vec<u8>) {
-                self.discriminant().ser_bin(output)
+        impl core::cmp::Ord for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            value, and when their values are equal, by their discriminant, making this a total \
+            order even when several variants share the same value")]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                core::cmp::Ord::cmp(indexed_valued_enums::valued_enum::Valued::value_ref(self), indexed_valued_enums::valued_enum::Valued::value_ref(other))
+                    .then_with(|| core::cmp::Ord::cmp(
+                        &indexed_valued_enums::indexed_enum::Indexed::discriminant(self),
+                        &indexed_valued_enums::indexed_enum::Indexed::discriminant(other)))
+            }
+        }
+
+        impl core::cmp::PartialOrd for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            value, and when their values are equal, by their discriminant")]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(core::cmp::Ord::cmp(self, other))
             }
         }
     };
-    (process feature $enum_name:ident, $value_type:ty; NanoDeBin)
+    (process feature $enum_name:ident, $value_type:ty; OrdByDiscriminant)
+    =>{
+        impl core::cmp::Ord for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            discriminant, this is, by their position of declaration, ignoring their fields")]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                core::cmp::Ord::cmp(
+                    &indexed_valued_enums::indexed_enum::Indexed::discriminant(self),
+                    &indexed_valued_enums::indexed_enum::Indexed::discriminant(other))
+            }
+        }
+
+        impl core::cmp::PartialOrd for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            discriminant, this is, by their position of declaration, ignoring their fields")]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(core::cmp::Ord::cmp(self, other))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; HashByDiscriminant)
+    =>{
+        impl core::hash::Hash for $enum_name {
+            #[doc = concat!("Hashes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant, ignoring it's fields, so variants differing only in their fields \
+            intentionally hash the same")]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(&indexed_valued_enums::indexed_enum::Indexed::discriminant(self), state)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; BTreeKey)
+    =>{
+        impl core::cmp::PartialEq for $enum_name {
+            #[doc = concat!("Checks whether these [",stringify!($enum_name),"]'s variants have \
+            the same discriminant, ignoring their fields")]
+            fn eq(&self, other: &Self) -> bool {
+                indexed_valued_enums::indexed_enum::Indexed::discriminant(self)
+                    == indexed_valued_enums::indexed_enum::Indexed::discriminant(other)
+            }
+        }
+
+        impl core::cmp::Eq for $enum_name {}
+
+        impl core::cmp::Ord for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            discriminant, ignoring their fields")]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                core::cmp::Ord::cmp(
+                    &indexed_valued_enums::indexed_enum::Indexed::discriminant(self),
+                    &indexed_valued_enums::indexed_enum::Indexed::discriminant(other))
+            }
+        }
+
+        impl core::cmp::PartialOrd for $enum_name {
+            #[doc = concat!("Compares these [",stringify!($enum_name),"]'s variants by their \
+            discriminant, ignoring their fields")]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl core::hash::Hash for $enum_name {
+            #[doc = concat!("Hashes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant, ignoring it's fields")]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(&indexed_valued_enums::indexed_enum::Indexed::discriminant(self), state)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DebugNameAndValue)
+    =>{
+        impl core::fmt::Debug for $enum_name {
+            #[doc = concat!("Formats this [",stringify!($enum_name),"]'s variant as it's name \
+            followed by it's value between parenthesis, like VariantName(value), this doesn't \
+            format the fields of the variant")]
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.debug_tuple(indexed_valued_enums::named_enum::Named::variant_name(self))
+                    .field(indexed_valued_enums::valued_enum::Valued::value_ref(self))
+                    .finish()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; TryFromDiscriminant)
+    =>{
+        impl core::convert::TryFrom<usize> for $enum_name {
+            type Error = indexed_valued_enums::indexed_enum::DiscriminantOutOfRange;
+
+            #[doc = concat!("Gets the [",stringify!($enum_name),"]'s variant corresponding to \
+            said discriminant, giving an error if there is no variant with such discriminant")]
+            fn try_from(discriminant: usize) -> Result<Self, Self::Error> {
+                <Self as indexed_valued_enums::indexed_enum::Indexed>::try_from_discriminant(discriminant)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; IntoDiscriminant)
+    =>{
+        impl core::convert::From<$enum_name> for usize {
+            #[doc = concat!("Gives the discriminant of this [",stringify!($enum_name),"]'s variant, \
+            consuming it without requiring it to be cloned")]
+            fn from(variant: $enum_name) -> Self {
+                indexed_valued_enums::indexed_enum::Indexed::discriminant(&variant)
+            }
+        }
+
+        impl core::convert::From<&$enum_name> for usize {
+            #[doc = concat!("Gives the discriminant of this [",stringify!($enum_name),"]'s variant")]
+            fn from(variant: &$enum_name) -> Self {
+                indexed_valued_enums::indexed_enum::Indexed::discriminant(variant)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Registry)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Pairs of the name and discriminant of every [",stringify!($enum_name),"]'s \
+            variant in order of discriminant, ready to register each variant by it's name")]
+            pub const REGISTRY: &'static [(&'static str, usize)] = &{
+                let names = <$enum_name as indexed_valued_enums::named_enum::Named>::VARIANT_NAMES;
+                let mut registry = [("", 0); <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT];
+                let mut discriminant = 0;
+                while discriminant < registry.len() {
+                    registry[discriminant] = (names[discriminant], discriminant);
+                    discriminant += 1;
+                }
+                registry
+            };
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Names)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Names of every [",stringify!($enum_name),"]'s variant in order of \
+            discriminant, where raw identifiers like r#type are named without 'r#'")]
+            pub const VARIANT_NAMES: &'static [&'static str] = <Self as indexed_valued_enums::named_enum::Named>::VARIANT_NAMES;
+
+            #[doc = concat!("Gives the name of this [",stringify!($enum_name),"]'s variant, where \
+            raw identifiers like r#type are named without 'r#', this operation is O(1)")]
+            pub const fn variant_name(&self) -> &'static str {
+                Self::VARIANT_NAMES[indexed_valued_enums::indexed_enum::discriminant_internal_sized(self)]
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Display)
+    =>{
+        impl core::fmt::Display for $enum_name {
+            #[doc = concat!("Formats this [",stringify!($enum_name),"]'s variant as it's name, \
+            taken from [indexed_valued_enums::named_enum::Named::VARIANT_NAMES]")]
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.pad(indexed_valued_enums::named_enum::Named::variant_name(self))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; FromStr)
+    =>{
+        impl core::str::FromStr for $enum_name {
+            type Err = indexed_valued_enums::named_enum::ParseVariantError;
+
+            #[doc = concat!("Parses the [",stringify!($enum_name),"]'s variant with this name, \
+            as found in [indexed_valued_enums::named_enum::Named::VARIANT_NAMES]")]
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                indexed_valued_enums::named_enum::Named::from_variant_name_opt(name)
+                    .ok_or(indexed_valued_enums::named_enum::ParseVariantError)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DisplayNameValue)
+    =>{
+        impl core::fmt::Display for $enum_name {
+            #[doc = concat!("Formats this [",stringify!($enum_name),"]'s variant as it's name \
+            followed by it's value, like 'VariantName = value'")]
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(formatter, "{} = {}", indexed_valued_enums::named_enum::Named::variant_name(self),
+                    indexed_valued_enums::valued_enum::Valued::value_ref(self))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Bitset)
+    =>{
+        const _: () = assert!(<$enum_name as indexed_valued_enums::indexed_enum::Indexed>::IS_FIELD_LESS,
+            concat!("The feature Bitset can't be used on ", stringify!($enum_name),
+            " as some of its variants have fields, which would be lost on the bitset"));
+
+        impl $enum_name {
+            #[doc = concat!("Packs these [",stringify!($enum_name),"]'s variants into a bitmask, \
+            where each bit is the presence of the variant whose discriminant is the bit's position")]
+            pub fn to_bitset(variants: &[Self]) -> u64 {
+                let mut set = indexed_valued_enums::set::VariantSet::new();
+                variants.iter().for_each(|variant| { set.insert(variant); });
+                set.bits()
+            }
+
+            #[doc = concat!("Unpacks the [",stringify!($enum_name),"]'s variants present in this \
+            bitmask, giving [None] if it has bits beyond the enum's variants")]
+            pub const fn from_bitset(bitset: u64) -> Option<indexed_valued_enums::set::VariantSet<Self>> {
+                indexed_valued_enums::set::VariantSet::from_bits(bitset)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DefaultFirst)
+    =>{
+        const _: () = assert!(<$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT > 0,
+            concat!("The feature DefaultFirst can't be used on ", stringify!($enum_name),
+            " as it doesn't have any variant"));
+
+        impl core::default::Default for $enum_name {
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant with the lowest \
+            discriminant, along with the contents of its fields in [indexed_valued_enums::indexed_enum::Indexed::VARIANTS]")]
+            fn default() -> Self {
+                indexed_valued_enums::indexed_enum::from_discriminant_internal(0)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Default)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{default variant $enum_name; 0}
+    };
+    (default variant $enum_name:ident; $discriminant:literal)
+    =>{
+        const _: () = assert!(<$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT > $discriminant,
+            concat!("The feature Default can't be used on ", stringify!($enum_name),
+            " as it doesn't have any variant"));
+
+        impl core::default::Default for $enum_name {
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s default variant, along with \
+            the contents of its fields in [indexed_valued_enums::indexed_enum::Indexed::VARIANTS]")]
+            fn default() -> Self {
+                indexed_valued_enums::indexed_enum::from_discriminant_internal($discriminant)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SequenceCompat)
+    =>{
+        indexed_valued_enums::create_sequence_compat !{$enum_name}
+    };
+    (process feature $enum_name:ident, $value_type:ty; ConstTraitImpls)
+    =>{
+        indexed_valued_enums::create_const_trait_impls !{$enum_name}
+    };
+    (process feature $enum_name:ident, $value_type:ty; SumValues)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Sums the values of every [",stringify!($enum_name),"]'s variant, \
+            this requires [",stringify!($value_type),"] to implement [Copy] and \
+            [core::iter::Sum]")]
+            pub fn sum_of_all_values() -> $value_type {
+                <Self as indexed_valued_enums::valued_enum::Valued>::VALUES.iter().copied().sum()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; ProductValues)
+    =>{
+        impl $enum_name {
+            #[doc = concat!("Multiplies the values of every [",stringify!($enum_name),"]'s variant, \
+            this requires [",stringify!($value_type),"] to implement [Copy] and \
+            [core::iter::Product]")]
+            pub fn product_of_all_values() -> $value_type {
+                <Self as indexed_valued_enums::valued_enum::Valued>::VALUES.iter().copied().product()
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Clone)
+    =>{
+        impl core::clone::Clone for $enum_name {
+
+            #[doc = concat!("Clones this [",stringify!($enum_name),"]'s variant<br><br>This clone \
+            is taken from the constant array of\
+            [indexed_valued_enums::indexed_enum::Indexed::VARIANTS], meaning this is a copy of that \
+            array, and therefore not causing a long macro expansion")]
+            fn clone(&self) -> Self {
+                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(self);
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant(discriminant)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Serialize)
+    =>{
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant, reducing its serializing complexity")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                serializer.serialize_u128(indexed_valued_enums::indexed_enum::Indexed::discriminant(self) as u128)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; Deserialize)
+    =>{
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant, reducing its deserializing complexity")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                match deserializer.deserialize_u128(indexed_valued_enums::serde_compatibility::discriminant_visitor::DISCRIMINANT_VISITOR) {
+                    Ok(value) => {
+                        indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(value).ok_or_else(|| serde::de::Error::custom(
+                            "Deserialized an discriminant that is bigger than the amount of variants",
+                        ))
+                    }
+                    Err(error) => { Err(error) }
+                }
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; CompactDiscriminant)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{assert discriminant width $enum_name; u32; "CompactDiscriminant"}
+
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant using the smallest of u8, u16 or u32 fitting every discriminant")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(self);
+                match <Self as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT {
+                    0..=0x100 => serializer.serialize_u8(discriminant as u8),
+                    0x101..=0x10000 => serializer.serialize_u16(discriminant as u16),
+                    _ => serializer.serialize_u32(discriminant as u32),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant using the smallest of u8, u16 or u32 fitting every discriminant")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                let visitor = indexed_valued_enums::serde_compatibility::discriminant_visitor::DISCRIMINANT_VISITOR;
+                let discriminant = match <Self as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT {
+                    0..=0x100 => deserializer.deserialize_u8(visitor)?,
+                    0x101..=0x10000 => deserializer.deserialize_u16(visitor)?,
+                    _ => deserializer.deserialize_u32(visitor)?,
+                };
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(discriminant).ok_or_else(|| serde::de::Error::custom(
+                    "Deserialized an discriminant that is bigger than the amount of variants",
+                ))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DeserializeLenient)
+    =>{
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant, be it written as a single number or as the first element of an array")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                match deserializer.deserialize_any(indexed_valued_enums::serde_compatibility::discriminant_visitor::DISCRIMINANT_VISITOR) {
+                    Ok(value) => {
+                        indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(value).ok_or_else(|| serde::de::Error::custom(
+                            "Deserialized an discriminant that is bigger than the amount of variants",
+                        ))
+                    }
+                    Err(error) => { Err(error) }
+                }
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SerializeValue)
+    =>{
+        impl serde::Serialize for $enum_name where for<'a> $value_type: serde::Serialize {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's value, \
+            so it can be read without knowing this enum")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                serde::Serialize::serialize(indexed_valued_enums::valued_enum::Valued::value_ref(self), serializer)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DeserializeValue)
+    =>{
+        impl<'de> serde::Deserialize<'de> for $enum_name where $value_type: serde::Deserialize<'de> + PartialEq {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            value, failing if no variant has said value")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                let value = <$value_type as serde::Deserialize>::deserialize(deserializer)?;
+                indexed_valued_enums::valued_enum::Valued::value_to_variant_opt(&value).ok_or_else(|| serde::de::Error::custom(
+                    "Deserialized a value that doesn't belong to any variant",
+                ))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SerializeNamed)
+    =>{
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's name")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                serializer.serialize_str(indexed_valued_enums::named_enum::Named::variant_name(self))
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SerializeUnitVariant)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{assert discriminant width $enum_name; u32; "SerializeUnitVariant"}
+
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as a unit \
+            variant of an enum, just like serde's derive would for a field-less enum")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                serializer.serialize_unit_variant(
+                    stringify!($enum_name),
+                    indexed_valued_enums::indexed_enum::Indexed::discriminant(self) as u32,
+                    indexed_valued_enums::named_enum::Named::variant_name(self),
+                )
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SerializeExternallyTagged)
+    =>{
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as a map whose \
+            single key is it's name and whose value is a unit, like serde's external tagging")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                let mut map = serializer.serialize_map(Some(1))?;
+                serde::ser::SerializeMap::serialize_entry(&mut map, indexed_valued_enums::named_enum::Named::variant_name(self), &())?;
+                serde::ser::SerializeMap::end(map)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from a map \
+            whose single key is it's name and whose value is a unit, like serde's external tagging")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserializer.deserialize_map(indexed_valued_enums::serde_compatibility::externally_tagged_visitor::ExternallyTaggedVisitor::new())
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DeserializeNamed)
+    =>{
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            name")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserializer.deserialize_str(indexed_valued_enums::serde_compatibility::name_visitor::NameVisitor::new())
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DeserializeByNameCaseInsensitive)
+    =>{
+        const _: () = assert!(
+            !indexed_valued_enums::named_enum::names_collide_ignoring_case(<$enum_name as indexed_valued_enums::named_enum::Named>::VARIANT_NAMES),
+            concat!("The feature DeserializeByNameCaseInsensitive can't be used on ", stringify!($enum_name),
+            " as some of its variants have the same name when ignoring case")
+        );
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            name, ignoring ASCII case differences")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserializer.deserialize_str(indexed_valued_enums::serde_compatibility::name_visitor::NameVisitor::ignoring_case())
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; DeserializeFlexible)
+    =>{
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from either \
+            it's discriminant or it's name")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                deserializer.deserialize_any(indexed_valued_enums::serde_compatibility::discriminant_or_name_visitor::DiscriminantOrNameVisitor::new())
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; SerdeHumanAware)
+    =>{
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's name \
+            on human readable formats, and as it's discriminant otherwise")]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(indexed_valued_enums::named_enum::Named::variant_name(self))
+                } else {
+                    serializer.serialize_u64(indexed_valued_enums::indexed_enum::Indexed::discriminant(self) as u64)
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            name on human readable formats, and from it's discriminant otherwise")]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                if deserializer.is_human_readable() {
+                    return deserializer.deserialize_str(indexed_valued_enums::serde_compatibility::name_visitor::NameVisitor::new());
+                }
+                match deserializer.deserialize_u64(indexed_valued_enums::serde_compatibility::discriminant_visitor::DISCRIMINANT_VISITOR) {
+                    Ok(value) => {
+                        indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(value).ok_or_else(|| serde::de::Error::custom(
+                            "Deserialized an discriminant that is bigger than the amount of variants",
+                        ))
+                    }
+                    Err(error) => { Err(error) }
+                }
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; NanoSerBin)
+    =>{
+        impl nanoserde::SerBin for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant, reducing its serializing complexity")]
+            fn ser_bin(&self, output: &mut Vec<u8>) {
+                indexed_valued_enums::indexed_enum::Indexed::discriminant(self).ser_bin(output)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; NanoCompactDiscriminant)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{assert discriminant width $enum_name; u32; "NanoCompactDiscriminant"}
+
+        impl nanoserde::SerBin for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant using the smallest of u8, u16 or u32 fitting every discriminant")]
+            fn ser_bin(&self, output: &mut Vec<u8>) {
+                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(self);
+                match <Self as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT {
+                    0..=0x100 => nanoserde::SerBin::ser_bin(&(discriminant as u8), output),
+                    0x101..=0x10000 => nanoserde::SerBin::ser_bin(&(discriminant as u16), output),
+                    _ => nanoserde::SerBin::ser_bin(&(discriminant as u32), output),
+                }
+            }
+        }
+
+        impl nanoserde::DeBin for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant using the smallest of u8, u16 or u32 fitting every discriminant")]
+            fn de_bin(offset: &mut usize, bytes: &[u8]) -> core::result::Result<Self, nanoserde::DeBinErr> {
+                let discriminant_offset = *offset;
+                let discriminant = match <Self as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT {
+                    0..=0x100 => <u8 as nanoserde::DeBin>::de_bin(offset, bytes)? as usize,
+                    0x101..=0x10000 => <u16 as nanoserde::DeBin>::de_bin(offset, bytes)? as usize,
+                    _ => <u32 as nanoserde::DeBin>::de_bin(offset, bytes)? as usize,
+                };
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(discriminant)
+                    .ok_or_else(|| nanoserde::DeBinErr {
+                        o: discriminant_offset,
+                        l: *offset - discriminant_offset,
+                        s: bytes.len(),
+                    })
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; NanoDeBin)
     =>{
         impl nanoserde::DeBin for $enum_name {
             #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
             discriminant, reducing its deserializing complexity")]
             fn de_bin(offset: &mut usize, bytes: &[u8]) -> core::result::Result<Self, nanoserde::DeBinErr> {
-                core::result::Result::Ok(
-                    $enum_name::from_discriminant_opt(nanoserde::DeBin::de_bin(offset, bytes)?)
-                        .ok_or_else(|| nanoserde::DeBinErr {
-                            o: *offset,
-                            l: core::mem::size_of::<usize>(),
-                            s: bytes.len(),
-                        })?)
+                let discriminant_offset = *offset;
+                let discriminant = nanoserde::DeBin::de_bin(offset, bytes)?;
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(discriminant)
+                    .ok_or_else(|| nanoserde::DeBinErr {
+                        o: discriminant_offset,
+                        l: *offset - discriminant_offset,
+                        s: bytes.len(),
+                    })
+            }
+        }
+
+        impl $enum_name {
+            #[doc = concat!("Deserializes a sequence of [",stringify!($enum_name),"]'s variants \
+            written as nanoserde does with a Vec, this is, their amount followed by their \
+            discriminants, failing if any discriminant doesn't belong to a variant")]
+            pub fn de_bin_vec(bytes: &[u8]) -> core::result::Result<Vec<Self>, nanoserde::DeBinErr> {
+                let mut offset = 0;
+                let len: usize = nanoserde::DeBin::de_bin(&mut offset, bytes)?;
+                // The length isn't trusted to reserve memory, as a corrupted one could be enormous
+                let mut variants = Vec::with_capacity(len.min(bytes.len()));
+                for _ in 0..len {
+                    variants.push(<Self as nanoserde::DeBin>::de_bin(&mut offset, bytes)?);
+                }
+                core::result::Result::Ok(variants)
             }
         }
     };
     (process feature $enum_name:ident, $value_type:ty; SerJson)
+    =>{
+        // Kept for compatibility, as this was the name of this feature before being documented as
+        // NanoSerJson
+        indexed_valued_enums::create_indexed_valued_enum !{process feature $enum_name, $value_type; NanoSerJson}
+    };
+    (process feature $enum_name:ident, $value_type:ty; NanoSerJson)
     =>{
         impl nanoserde::SerJson for $enum_name {
             #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
             discriminant, reducing its serializing complexity")]
             fn ser_json(&self, _d: usize, state: &mut nanoserde::SerJsonState) {
-                state.out.push_str(&self.discriminant().to_string());
+                state.out.push_str(&indexed_valued_enums::indexed_enum::Indexed::discriminant(self).to_string());
             }
         }
     };
@@ -311,7 +1400,7 @@ macro_rules! create_indexed_valued_enum {
                 state.next_tok(input)?;
                 let discriminant = val as usize;
 
-                let variant = $enum_name::from_discriminant_opt(discriminant)
+                let variant = indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(discriminant)
                     .ok_or_else(|| nanoserde::DeJsonErr{
                         msg: "Indicated discriminant doesn't not correspond to any variant of this enum".to_string(),
                         line: 0,
@@ -321,4 +1410,374 @@ macro_rules! create_indexed_valued_enum {
             }
         }
     };
-}
\ No newline at end of file
+    (process feature $enum_name:ident, $value_type:ty; NanoSerRon)
+    =>{
+        impl nanoserde::SerRon for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant, reducing its serializing complexity")]
+            fn ser_ron(&self, _d: usize, state: &mut nanoserde::SerRonState) {
+                state.out.push_str(&indexed_valued_enums::indexed_enum::Indexed::discriminant(self).to_string());
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; NanoDeRon)
+    =>{
+        impl nanoserde::DeRon for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant, reducing its deserializing complexity")]
+            fn de_ron(state: &mut nanoserde::DeRonState, input: &mut core::str::Chars) -> Result<Self, nanoserde::DeRonErr> {
+                let (line, col) = (state.line, state.col);
+                let val = state.u64_range(core::u64::MAX as u64)?;
+                state.next_tok(input)?;
+
+                usize::try_from(val).ok()
+                    .and_then(indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt)
+                    .ok_or_else(|| nanoserde::DeRonErr {
+                        msg: format!("Discriminant {} doesn't correspond to any variant of {}, as it has {} variants",
+                                     val, stringify!($enum_name),
+                                     <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT),
+                        line,
+                        col,
+                    })
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; BincodeEncode)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{assert discriminant width $enum_name; u32; "BincodeEncode"}
+
+        impl bincode::Encode for $enum_name {
+            #[doc = concat!("Encodes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant in an u32, reducing its encoding complexity")]
+            fn encode<E: bincode::enc::Encoder>(&self, encoder: &mut E) -> core::result::Result<(), bincode::error::EncodeError> {
+                bincode::Encode::encode(&(indexed_valued_enums::indexed_enum::Indexed::discriminant(self) as u32), encoder)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; BincodeDecode)
+    =>{
+        impl<Context> bincode::Decode<Context> for $enum_name {
+            #[doc = concat!("Decodes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant encoded in an u32, reducing its decoding complexity")]
+            fn decode<D: bincode::de::Decoder<Context = Context>>(decoder: &mut D) -> core::result::Result<Self, bincode::error::DecodeError> {
+                let discriminant = <u32 as bincode::Decode<Context>>::decode(decoder)?;
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(discriminant as usize)
+                    .ok_or_else(|| bincode::error::DecodeError::OtherString(
+                        "Decoded a discriminant that is bigger than the amount of variants".into(),
+                    ))
+            }
+        }
+
+        bincode::impl_borrow_decode!($enum_name);
+    };
+    (process feature $enum_name:ident, $value_type:ty; NumFromPrimitive)
+    =>{
+        impl num_traits::FromPrimitive for $enum_name {
+            #[doc = concat!("Gets the [",stringify!($enum_name),"]'s variant whose discriminant is \
+            this number, giving [None] if it's negative or there is no variant with such discriminant")]
+            fn from_i64(discriminant: i64) -> Option<Self> {
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(usize::try_from(discriminant).ok()?)
+            }
+
+            #[doc = concat!("Gets the [",stringify!($enum_name),"]'s variant whose discriminant is \
+            this number, giving [None] if there is no variant with such discriminant")]
+            fn from_u64(discriminant: u64) -> Option<Self> {
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(usize::try_from(discriminant).ok()?)
+            }
+        }
+    };
+    (process feature $enum_name:ident, $value_type:ty; NumToPrimitive)
+    =>{
+        impl num_traits::ToPrimitive for $enum_name {
+            #[doc = concat!("Gives the discriminant of this [",stringify!($enum_name),"]'s variant \
+            as an i64")]
+            fn to_i64(&self) -> Option<i64> {
+                i64::try_from(indexed_valued_enums::indexed_enum::Indexed::discriminant(self)).ok()
+            }
+
+            #[doc = concat!("Gives the discriminant of this [",stringify!($enum_name),"]'s variant \
+            as an u64")]
+            fn to_u64(&self) -> Option<u64> {
+                u64::try_from(indexed_valued_enums::indexed_enum::Indexed::discriminant(self)).ok()
+            }
+        }
+    };
+    (serialize discriminant as $enum_name:ident; $width:ident)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{assert discriminant width $enum_name; $width; "serialize_discriminant_as"}
+
+        impl serde::Serialize for $enum_name {
+            #[doc = concat!("Serializes this [",stringify!($enum_name),"]'s variant as it's \
+            discriminant written as an ", stringify!($width))]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(self) as $width;
+                serde::Serialize::serialize(&discriminant, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $enum_name {
+            #[doc = concat!("Deserializes this [",stringify!($enum_name),"]'s variant from it's \
+            discriminant written as an ", stringify!($width))]
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                let discriminant = <$width as serde::Deserialize>::deserialize(deserializer)?;
+                usize::try_from(discriminant).ok()
+                    .and_then(indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt)
+                    .ok_or_else(|| serde::de::Error::custom(
+                        "Deserialized an discriminant that is bigger than the amount of variants",
+                    ))
+            }
+        }
+    };
+    (assert discriminant width $enum_name:ident; $width:ident; $feature:literal)
+    =>{
+        const _: () = assert!(
+            <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT as u128 <= (<$width>::MAX as u128).saturating_add(1),
+            concat!("The discriminants of ", stringify!($enum_name), " can't be serialized as ", stringify!($width),
+            " through ", $feature, ", as it has more variants than ", stringify!($width), " can represent")
+        );
+    };
+}
+
+/// Produces an enum implementing the [Indexed] trait without any value, meaning the enum's variants
+/// can produce unique numbers of usize to identify each variant through [Indexed::discriminant],
+/// and get back those variants through [Indexed::from_discriminant], this is useful when you don't
+/// have meaningful values to attach to your variants.
+///
+/// It follows the same pattern as [create_indexed_valued_enum], except the enum isn't valued as
+/// any type and variants are written without values:
+///
+/// ```rust
+/// use indexed_valued_enums::create_indexed_enum;
+///
+/// create_indexed_enum! {
+///     #[derive(PartialEq, Debug)]
+///     ###[features(Clone, Delegators)]
+///     pub enum Weekday;
+///     Monday,
+///     Tuesday,
+///     Wednesday
+/// }
+///
+/// assert_eq!(Weekday::Tuesday.discriminant(), 1);
+/// assert_eq!(Weekday::from_discriminant(2), Weekday::Wednesday);
+/// ```
+///
+/// Only the features that don't rely on values can be used, like 'Clone', 'Delegators' (giving
+/// just the functions over discriminants), 'Serialize' or 'Deserialize'.
+#[macro_export]
+macro_rules! create_indexed_enum {
+    (
+        $(#[$metadata:meta])*
+        $(##[features($($features:tt),*)])?
+        $visibility:vis enum $enum_name:ident;
+        $($(#[$($variants_metadata:tt)*])* $variants:ident),+ $(,)?
+    ) => {
+        $(indexed_valued_enums::create_indexed_valued_enum !{check variant attributes $variants;
+            $([$($variants_metadata)*])*})+
+
+        $(#[$metadata])*
+        #[repr(usize)]
+        $visibility enum $enum_name{
+            $($(#[$($variants_metadata)*])* $variants),+,
+        }
+
+        #[allow(deprecated)]
+        impl indexed_valued_enums::indexed_enum::Indexed for $enum_name {
+            #[doc = concat!("Array storing all the variants of the [",stringify!($enum_name),"]\
+            enum where each variant is stored in ordered by their discriminant")]
+            const VARIANTS: &'static [ Self ] = &[$($enum_name::$variants),+];
+
+            #[doc = concat!("Whether none of the variants of the [",stringify!($enum_name),"] \
+            enum have fields")]
+            const IS_FIELD_LESS: bool = true;
+        }
+
+        $($(indexed_valued_enums::create_indexed_enum !{process feature $enum_name; $features})*)?
+    };
+    (process feature $enum_name:ident; Delegators)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{delegators $enum_name, (); Delegators [
+            discriminant from_discriminant_opt from_discriminant eq_discriminant
+            first last neighbors dispatch_value] []}
+    };
+    (process feature $enum_name:ident; $feature:tt)
+    =>{
+        indexed_valued_enums::create_indexed_valued_enum !{process feature $enum_name, (); $feature}
+    };
+}
+
+/// Produces a test named after your enum checking the invariants this crate relies on, which are
+/// that every variant's discriminant is its position in [Indexed::VARIANTS] and can be taken back
+/// through [Indexed::from_discriminant], and that there are as many values in [Valued::VALUES] as
+/// variants, writing 'unique values' after the enum also checks that no value is repeated, which
+/// requires the values to implement [PartialEq], this is useful when implementing these traits
+/// manually or when values are generated:
+///
+/// ```rust
+/// use indexed_valued_enums::{assert_indexed_valued_invariants, create_indexed_valued_enum};
+///
+/// create_indexed_valued_enum! {
+///     enum Planet valued as f32;
+///     Earth, 9.807,
+///     Mars, 3.71,
+///     Mercury, 3.7
+/// }
+///
+/// assert_indexed_valued_invariants!(Planet, unique values);
+/// ```
+#[macro_export]
+macro_rules! assert_indexed_valued_invariants {
+    ($enum_name:ident $(,)?) => {
+        indexed_valued_enums::assert_indexed_valued_invariants !{$enum_name; {}}
+    };
+    ($enum_name:ident, unique values $(,)?) => {
+        indexed_valued_enums::assert_indexed_valued_invariants !{$enum_name; {
+            let values = <$enum_name as indexed_valued_enums::valued_enum::Valued>::VALUES;
+            for (discriminant, value) in values.iter().enumerate() {
+                if let Some(repeated) = values[..discriminant].iter().position(|previous| previous == value) {
+                    panic!("The variants with discriminants {} and {} of {} have the same value",
+                        repeated, discriminant, stringify!($enum_name));
+                }
+            }
+        }}
+    };
+    ($enum_name:ident; $extra_checks:block) => {
+        #[test]
+        #[allow(non_snake_case)]
+        fn $enum_name() {
+            let variants = <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANTS;
+            let values = <$enum_name as indexed_valued_enums::valued_enum::Valued>::VALUES;
+            assert_eq!(variants.len(), values.len(), "{} has {} variants but {} values",
+                stringify!($enum_name), variants.len(), values.len());
+            for (position, variant) in variants.iter().enumerate() {
+                let discriminant = indexed_valued_enums::indexed_enum::Indexed::discriminant(variant);
+                assert_eq!(discriminant, position, "The variant at position {} of {} has the discriminant {}",
+                    position, stringify!($enum_name), discriminant);
+                let variant = <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::from_discriminant(discriminant);
+                assert_eq!(indexed_valued_enums::indexed_enum::Indexed::discriminant(&variant), discriminant,
+                    "The variant of {} got from the discriminant {} has a different discriminant",
+                    stringify!($enum_name), discriminant);
+            }
+            $extra_checks
+        }
+    };
+}
+
+/// Builds the static map of the 'ValueIndex' feature, associating each value to their variant's
+/// discriminant through the phf crate, this is used internally by [create_indexed_valued_enum].
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_value_index {
+    ($enum_name:ident $value_type:ty; $($variants:ident, $values:expr),+) => {
+        impl $enum_name {
+            #[doc = concat!("Gives [",stringify!($enum_name),"]'s variant corresponding to this \
+            value <br><br> this is an O(1) operation as it looks up the value on a static map \
+            built at compile time through phf")]
+            pub fn value_to_variant_indexed_opt(value: &$value_type) -> Option<Self> {
+                static VALUE_INDEX: phf::Map<$value_type, usize> = phf::phf_map! {
+                    $($values => $enum_name::$variants as usize),+
+                };
+                indexed_valued_enums::indexed_enum::Indexed::from_discriminant_opt(*VALUE_INDEX.get(value)?)
+            }
+
+            #[doc = concat!("Gives [",stringify!($enum_name),"]'s variant corresponding to this \
+            value <br><br> this is an O(1) operation as it looks up the value on a static map \
+            built at compile time through phf")]
+            pub fn value_to_variant_indexed(value: &$value_type) -> Self {
+                Self::value_to_variant_indexed_opt(value).unwrap()
+            }
+        }
+    };
+}
+
+/// Builds the static map of the 'ValueIndex' feature, which requires the 'phf' feature of this
+/// crate, this is used internally by [create_indexed_valued_enum].
+#[cfg(not(feature = "phf"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_value_index {
+    ($($tokens:tt)*) => {
+        compile_error!("The feature ValueIndex requires the feature 'phf' of indexed_valued_enums, like \
+        indexed_valued_enums = { version = \"1.0.0\", features=[\"phf\"] }");
+    };
+}
+
+/// Implements enum-iterator's Sequence trait for the 'SequenceCompat' feature, this is used
+/// internally by [create_indexed_valued_enum].
+#[cfg(feature = "enum_iterator_compat")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_sequence_compat {
+    ($enum_name:ident) => {
+        impl enum_iterator::Sequence for $enum_name {
+            #[doc = concat!("Amount of variants of the [",stringify!($enum_name),"] enum")]
+            const CARDINALITY: usize = <$enum_name as indexed_valued_enums::indexed_enum::Indexed>::VARIANT_COUNT;
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant whose discriminant \
+            follows this one, this operation is O(1)")]
+            fn next(&self) -> Option<Self> {
+                indexed_valued_enums::indexed_enum::Indexed::next_variant(self)
+            }
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant whose discriminant \
+            precedes this one, this operation is O(1)")]
+            fn previous(&self) -> Option<Self> {
+                indexed_valued_enums::indexed_enum::Indexed::previous_variant(self)
+            }
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant with the lowest \
+            discriminant, this operation is O(1)")]
+            fn first() -> Option<Self> {
+                <Self as indexed_valued_enums::indexed_enum::Indexed>::first()
+            }
+
+            #[doc = concat!("Gives the [",stringify!($enum_name),"]'s variant with the highest \
+            discriminant, this operation is O(1)")]
+            fn last() -> Option<Self> {
+                <Self as indexed_valued_enums::indexed_enum::Indexed>::last()
+            }
+        }
+    };
+}
+
+/// Implements enum-iterator's Sequence trait for the 'SequenceCompat' feature, which requires the
+/// 'enum_iterator_compat' feature of this crate, this is used internally by
+/// [create_indexed_valued_enum].
+#[cfg(not(feature = "enum_iterator_compat"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_sequence_compat {
+    ($($tokens:tt)*) => {
+        compile_error!("The feature SequenceCompat requires the feature 'enum_iterator_compat' of \
+        indexed_valued_enums, like indexed_valued_enums = { version = \"1.0.0\", \
+        features=[\"enum_iterator_compat\"] }");
+    };
+}
+
+/// Implements the const traits of [crate::const_traits] as `impl const` for the 'ConstTraitImpls'
+/// feature, this is used internally by [create_indexed_valued_enum].
+#[cfg(feature = "const_trait")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_const_trait_impls {
+    ($enum_name:ident) => {
+        impl const indexed_valued_enums::const_traits::ConstIndexed for $enum_name {}
+
+        impl const indexed_valued_enums::const_traits::ConstValued for $enum_name {}
+    };
+}
+
+/// Implements the const traits of [crate::const_traits] as `impl const` for the 'ConstTraitImpls'
+/// feature, which requires the 'const_trait' feature of this crate and a nightly compiler, this
+/// is used internally by [create_indexed_valued_enum].
+#[cfg(not(feature = "const_trait"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! create_const_trait_impls {
+    ($($tokens:tt)*) => {
+        compile_error!("The feature ConstTraitImpls requires the feature 'const_trait' of \
+        indexed_valued_enums, like indexed_valued_enums = { version = \"1.0.0\", \
+        features=[\"const_trait\"] }, as well as a nightly compiler");
+    };
+}
@@ -0,0 +1,101 @@
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+use crate::indexed_enum::Indexed;
+
+/// Table associating a value of type T to every variant of an enum implementing [Indexed], where
+/// each value is stored in the position of it's variant's discriminant, meaning getting the value
+/// of a variant is an O(1) operation.
+///
+/// This is useful when you need to associate more data to your variants after the fact, for
+/// example, when these values can't be const and therefore can't be part of
+/// [crate::valued_enum::Valued::VALUES], like in the following example, where a table of weights is
+/// built at runtime:
+///
+/// ```rust
+/// use indexed_valued_enums::{create_indexed_valued_enum, map::EnumMap};
+/// use indexed_valued_enums::{indexed_enum::Indexed, valued_enum::Valued};
+///
+/// create_indexed_valued_enum! {
+///     #[derive(PartialEq, Debug)]
+///     enum Planet valued as f32;
+///     Earth, 9.807,
+///     Mars, 3.71,
+///     Mercury, 3.7
+/// }
+///
+/// let weights: EnumMap<Planet, f32, 3> = Planet::remap(|planet| 80.0 * planet.value());
+/// assert_eq!(weights[Planet::Mars], 80.0 * 3.71);
+/// ```
+///
+/// The const parameter N must be the amount of variants of the enum, as given by
+/// [Indexed::VARIANT_COUNT], otherwise it will fail to compile when building the map.
+pub struct EnumMap<TIndexed: Indexed, T, const N: usize> {
+    values: [T; N],
+    _indexed: PhantomData<TIndexed>,
+}
+
+impl<TIndexed: Indexed, T, const N: usize> EnumMap<TIndexed, T, N> {
+    const LENGTH_MATCHES_VARIANTS: () = assert!(N == TIndexed::VARIANT_COUNT,
+        "The length of an EnumMap must be the same as the amount of variants of it's enum");
+
+    /// Creates a map from an array of values, where each value is stored in the same position as
+    /// the discriminant of the variant they belong to.
+    pub const fn from_array(values: [T; N]) -> Self {
+        let () = Self::LENGTH_MATCHES_VARIANTS;
+        Self { values, _indexed: PhantomData }
+    }
+
+    /// Creates a map by calling the function on every variant in order of discriminant, storing
+    /// each result as the value of said variant.
+    pub fn from_fn<TFunction: FnMut(TIndexed) -> T>(mut function: TFunction) -> Self {
+        let () = Self::LENGTH_MATCHES_VARIANTS;
+        Self {
+            values: core::array::from_fn(|discriminant| function(TIndexed::from_discriminant(discriminant))),
+            _indexed: PhantomData,
+        }
+    }
+
+    /// Gets a reference to the value associated to this variant, this operation is O(1).
+    pub fn get(&self, variant: &TIndexed) -> &T {
+        &self.values[variant.discriminant()]
+    }
+
+    /// Gets a mutable reference to the value associated to this variant, this operation is O(1).
+    pub fn get_mut(&mut self, variant: &TIndexed) -> &mut T {
+        &mut self.values[variant.discriminant()]
+    }
+
+    /// Gives the values of this map, where each value is stored in the same position as the
+    /// discriminant of the variant they belong to.
+    pub const fn as_array(&self) -> &[T; N] {
+        &self.values
+    }
+
+    /// Consumes this map giving back it's values, where each value is stored in the same position
+    /// as the discriminant of the variant they belong to.
+    pub fn into_array(self) -> [T; N] {
+        self.values
+    }
+
+    /// Iterates over every variant along it's associated value in order of discriminant.
+    pub fn iter(&self) -> impl Iterator<Item=(TIndexed, &T)> {
+        self.values.iter()
+            .enumerate()
+            .map(|(discriminant, value)| (TIndexed::from_discriminant(discriminant), value))
+    }
+}
+
+impl<TIndexed: Indexed, T, const N: usize> Index<TIndexed> for EnumMap<TIndexed, T, N> {
+    type Output = T;
+
+    fn index(&self, variant: TIndexed) -> &Self::Output {
+        self.get(&variant)
+    }
+}
+
+impl<TIndexed: Indexed, T, const N: usize> IndexMut<TIndexed> for EnumMap<TIndexed, T, N> {
+    fn index_mut(&mut self, variant: TIndexed) -> &mut Self::Output {
+        self.get_mut(&variant)
+    }
+}
@@ -0,0 +1,145 @@
+use crate::indexed_enum::{discriminant_internal_sized, Indexed};
+
+/// Allows to get the name of an enum's variant, where this enum implements [Indexed], for example,
+/// having the following implementation:
+///
+/// ```rust
+/// use indexed_valued_enums::{indexed_enum::Indexed, named_enum::Named};
+///
+///
+/// enum Number{ First, Second, Third }
+///
+/// impl Indexed for Number{
+///     const VARIANTS: &'static [Self] = &[Number::First, Number::Second, Number::Third];
+/// }
+///
+/// impl Named for Number{
+///     const VARIANT_NAMES: &'static [&'static str] = &["First", "Second", "Third"];
+/// }
+/// ```
+/// Calling [Named::variant_name] on every enum produces [First->"First", Second->"Second",
+/// Third->"Third"], and calling [Named::from_variant_name_opt] gives these variants back.
+///
+/// Note this documentation it's solely informational, it is dis-recommended to implement this trait
+/// manually, but using the derive macro [crate::Valued] or the declarative macro
+/// [crate::create_indexed_valued_enum] instead.
+pub trait Named: Indexed {
+    /// Names of every variant, each name must be stored to match it's corresponding variant, this
+    /// means it must be sorted in the same order as [Indexed::VARIANTS]
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Gives the name of this variant, this is an O(1) operation as it just gets the name from
+    /// [Named::VARIANT_NAMES]
+    fn variant_name(&self) -> &'static str {
+        Self::VARIANT_NAMES[self.discriminant()]
+    }
+
+    /// Gives both the discriminant and the name of this variant as (discriminant, name), which is
+    /// useful to build logging or debugging tables, this is an O(1) operation.
+    ///
+    /// A const version of this function is given by the 'Delegators' feature.
+    fn label(&self) -> (usize, &'static str) {
+        label_internal(self)
+    }
+
+    /// Gives the discriminant of the variant with this name, this is an O(n) operation as it does
+    /// so by comparing every single name contained in [Named::VARIANT_NAMES]
+    ///
+    /// A const version of this function is [discriminant_of_name_internal].
+    fn discriminant_of_name(name: &str) -> Option<usize> {
+        discriminant_of_name_internal::<Self>(name)
+    }
+
+    /// Gives the variant with this name, this is an O(n) operation as it does so by comparing
+    /// every single name contained in [Named::VARIANT_NAMES]
+    fn from_variant_name_opt(name: &str) -> Option<Self> {
+        let discriminant = Self::VARIANT_NAMES.iter().position(|variant_name| name.eq(*variant_name));
+        Self::from_discriminant_opt(discriminant?)
+    }
+
+    /// Gives the variant with this name ignoring ASCII case differences, meaning "mars", "Mars" and
+    /// "MARS" are all resolved to the same variant, this is an O(n) operation as it does so by
+    /// comparing every single name contained in [Named::VARIANT_NAMES]
+    fn from_variant_name_ignore_case_opt(name: &str) -> Option<Self> {
+        let discriminant = Self::VARIANT_NAMES.iter().position(|variant_name| name.eq_ignore_ascii_case(variant_name));
+        Self::from_discriminant_opt(discriminant?)
+    }
+}
+
+/// Error produced when parsing a name that doesn't belong to any variant, as through the feature
+/// 'FromStr', it doesn't keep the name that failed to parse, so it can be used without allocating.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseVariantError;
+
+impl core::fmt::Display for ParseVariantError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("The name doesn't match the name of any variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVariantError {}
+
+/// Gives both the discriminant and the name of this variant as (discriminant, name), this is an
+/// O(1) operation as it just gets the name from [Named::VARIANT_NAMES].
+///
+/// This internal function is used when using 'Delegators'.
+pub const fn label_internal<TNamed: Named>(variant: &TNamed) -> (usize, &'static str) {
+    let discriminant = discriminant_internal_sized(variant);
+    (discriminant, TNamed::VARIANT_NAMES[discriminant])
+}
+
+/// Gives the discriminant of the variant with this name, this is an O(n) operation as it does so
+/// by comparing every single name contained in [Named::VARIANT_NAMES] byte by byte.
+///
+/// Being const, this allows to resolve a name to a discriminant at compile time, for example, to
+/// index a const array by the name of a variant.
+pub const fn discriminant_of_name_internal<TNamed: Named>(name: &str) -> Option<usize> {
+    let mut discriminant = 0;
+    while discriminant < TNamed::VARIANT_NAMES.len() {
+        if eq_bytes(TNamed::VARIANT_NAMES[discriminant].as_bytes(), name.as_bytes()) {
+            return Some(discriminant);
+        }
+        discriminant += 1;
+    }
+    None
+}
+
+const fn eq_bytes(first: &[u8], second: &[u8]) -> bool {
+    if first.len() != second.len() { return false; }
+    let mut index = 0;
+    while index < first.len() {
+        if first[index] != second[index] { return false; }
+        index += 1;
+    }
+    true
+}
+
+/// Removes the 'r#' prefix of a raw identifier, so the name of a variant written as r#type is
+/// "type", other identifiers are given back unchanged.
+pub const fn unraw_identifier(identifier: &'static str) -> &'static str {
+    match identifier.as_bytes() {
+        [b'r', b'#', name @ ..] => match core::str::from_utf8(name) {
+            Ok(name) => name,
+            Err(_) => identifier,
+        },
+        _ => identifier,
+    }
+}
+
+/// Checks whether any two of these names are the same when ignoring ASCII case differences, this
+/// is used at compile time to reject enums whose variants can't be told apart case-insensitively.
+pub const fn names_collide_ignoring_case(names: &[&str]) -> bool {
+    let mut first_index = 0;
+    while first_index < names.len() {
+        let mut second_index = first_index + 1;
+        while second_index < names.len() {
+            if names[first_index].eq_ignore_ascii_case(names[second_index]) {
+                return true;
+            }
+            second_index += 1;
+        }
+        first_index += 1;
+    }
+    false
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Deserializer, Serializer};
+use serde::de::Error;
+
+use crate::indexed_enum::Indexed;
+use crate::set::VariantSet;
+
+/// Serializes these variants as a single integer, where each bit is the presence of the variant
+/// whose discriminant is the bit's position, meant to be used through serde's attribute
+/// ```#[serde(with = "indexed_valued_enums::serde_compatibility::bitset")]```.
+///
+/// Just like [VariantSet], the enum can't have more than 64 variants.
+pub fn serialize<'variants, TIndexed, S>(variants: impl IntoIterator<Item=&'variants TIndexed>, serializer: S) -> Result<S::Ok, S::Error>
+    where TIndexed: Indexed + 'variants, S: Serializer {
+    let mut set = VariantSet::<TIndexed>::new();
+    variants.into_iter().for_each(|variant| { set.insert(variant); });
+    serializer.serialize_u64(set.bits())
+}
+
+/// Deserializes a bitset into any collection of variants ordered by discriminant, failing if the
+/// bitset has bits beyond the enum's variants, since the variants are taken from
+/// [Indexed::VARIANTS], their fields aren't kept.
+pub fn deserialize<'de, TIndexed, TVariants, D>(deserializer: D) -> Result<TVariants, D::Error>
+    where TIndexed: Indexed, TVariants: FromIterator<TIndexed>, D: Deserializer<'de> {
+    let bits = u64::deserialize(deserializer)?;
+    VariantSet::<TIndexed>::from_bits(bits)
+        .map(|set| set.into_iter().collect())
+        .ok_or_else(|| D::Error::custom("Deserialized a bitset containing variants that don't exist"))
+}
@@ -0,0 +1,44 @@
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+
+use serde::de::{Error, Visitor};
+
+use crate::named_enum::Named;
+
+///Visitor to deserialize a variant from either it's discriminant or it's name
+pub struct DiscriminantOrNameVisitor<TNamed: Named> {
+    _named: PhantomData<TNamed>,
+}
+
+impl<TNamed: Named> DiscriminantOrNameVisitor<TNamed> {
+    ///Creates a visitor accepting both the discriminants and the exact names of the variants
+    pub const fn new() -> Self {
+        Self { _named: PhantomData }
+    }
+}
+
+impl<TNamed: Named> Default for DiscriminantOrNameVisitor<TNamed> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'de, TNamed: Named> Visitor<'de> for DiscriminantOrNameVisitor<TNamed> {
+    type Value = TNamed;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("Value was supposed to be the discriminant or the name of a variant")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> where E: Error {
+        usize::try_from(v).ok()
+            .and_then(TNamed::from_discriminant_opt)
+            .ok_or_else(|| E::custom(
+                "Deserialized an discriminant that is bigger than the amount of variants",
+            ))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: Error {
+        TNamed::from_variant_name_opt(v).ok_or_else(|| E::unknown_variant(v, TNamed::VARIANT_NAMES))
+    }
+}
@@ -1,4 +1,4 @@
-use serde::de::{Error, Visitor};
+use serde::de::{Error, SeqAccess, Visitor};
 
 ///Visitor to deserialize usize
 pub const DISCRIMINANT_VISITOR: USizediscriminantVisitor = USizediscriminantVisitor;
@@ -6,7 +6,7 @@ pub const DISCRIMINANT_VISITOR: USizediscriminantVisitor = USizediscriminantVisi
 ///Empty struct of a visitor that deserialize to a single usize
 pub struct USizediscriminantVisitor;
 
-impl Visitor<'_> for USizediscriminantVisitor {
+impl<'de> Visitor<'de> for USizediscriminantVisitor {
     type Value = usize;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -52,4 +52,10 @@ impl Visitor<'_> for USizediscriminantVisitor {
     fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> where E: Error {
         v.try_into().map_err(|_| E::custom("Value not in usize's range"))
     }
-}
+
+    ///Takes the first element of a sequence as the discriminant, as some encoders wrap it in a
+    ///single-element array
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: SeqAccess<'de> {
+        seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use core::marker::PhantomData;
+
+use serde::de::{Error, IgnoredAny, MapAccess, Visitor};
+
+use crate::named_enum::Named;
+use crate::serde_compatibility::name_visitor::NameVisitor;
+
+///Visitor to deserialize a variant from a map whose single key is it's name and whose value is a
+///unit, like ```{"First": null}```
+pub struct ExternallyTaggedVisitor<TNamed: Named> {
+    _named: PhantomData<TNamed>,
+}
+
+impl<TNamed: Named> ExternallyTaggedVisitor<TNamed> {
+    ///Creates a visitor that only accepts the exact names of the variants as the key of the map
+    pub const fn new() -> Self {
+        Self { _named: PhantomData }
+    }
+}
+
+impl<TNamed: Named> Default for ExternallyTaggedVisitor<TNamed> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'de, TNamed: Named> Visitor<'de> for ExternallyTaggedVisitor<TNamed> {
+    type Value = TNamed;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("Value was supposed to be a map with the name of a variant as it's only key")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: MapAccess<'de> {
+        let variant = map.next_key_seed(NameVisitor::<TNamed>::new())?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        map.next_value::<()>()?;
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(2, &self));
+        }
+        Ok(variant)
+    }
+}
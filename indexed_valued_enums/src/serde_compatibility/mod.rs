@@ -1,2 +1,14 @@
 //Defines a visitor to deserialize usize
-pub mod discriminant_visitor;
\ No newline at end of file
+pub mod discriminant_visitor;
+
+//Defines a visitor to deserialize a variant from it's name
+pub mod name_visitor;
+
+//Defines a visitor to deserialize a variant from either it's discriminant or it's name
+pub mod discriminant_or_name_visitor;
+
+//Defines a visitor to deserialize a variant from a map whose single key is it's name
+pub mod externally_tagged_visitor;
+
+//Defines functions to de/serialize a collection of variants as a single bitset
+pub mod bitset;
\ No newline at end of file
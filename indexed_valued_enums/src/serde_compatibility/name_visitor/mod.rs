@@ -0,0 +1,54 @@
+use core::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, Deserializer, Error, Visitor};
+
+use crate::named_enum::Named;
+
+///Visitor to deserialize a variant from it's name
+pub struct NameVisitor<TNamed: Named> {
+    ignore_case: bool,
+    _named: PhantomData<TNamed>,
+}
+
+impl<TNamed: Named> NameVisitor<TNamed> {
+    ///Creates a visitor that only accepts the exact names of the variants
+    pub const fn new() -> Self {
+        Self { ignore_case: false, _named: PhantomData }
+    }
+
+    ///Creates a visitor that accepts the names of the variants ignoring ASCII case differences
+    pub const fn ignoring_case() -> Self {
+        Self { ignore_case: true, _named: PhantomData }
+    }
+}
+
+impl<TNamed: Named> Default for NameVisitor<TNamed> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'de, TNamed: Named> Visitor<'de> for NameVisitor<TNamed> {
+    type Value = TNamed;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("Value was supposed to be the name of a variant")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: Error {
+        let variant = if self.ignore_case {
+            TNamed::from_variant_name_ignore_case_opt(v)
+        } else {
+            TNamed::from_variant_name_opt(v)
+        };
+        variant.ok_or_else(|| E::unknown_variant(v, TNamed::VARIANT_NAMES))
+    }
+}
+
+impl<'de, TNamed: Named> DeserializeSeed<'de> for NameVisitor<TNamed> {
+    type Value = TNamed;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(self)
+    }
+}
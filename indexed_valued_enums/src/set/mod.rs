@@ -0,0 +1,186 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::indexed_enum::Indexed;
+
+/// Set of variants of an enum implementing [Indexed], stored as a bitmask where each bit is the
+/// presence of the variant whose discriminant is the bit's position, meaning inserting, removing
+/// and checking variants are O(1) operations.
+///
+/// Since the bitmask is an u64, the enum can't have more than 64 variants, otherwise it will fail
+/// to compile when creating the set.
+///
+/// ```rust
+/// use indexed_valued_enums::{create_indexed_valued_enum, set::VariantSet};
+///
+/// create_indexed_valued_enum! {
+///     #[derive(PartialEq, Debug)]
+///     enum Planet valued as f32;
+///     Earth, 9.807,
+///     Mars, 3.71,
+///     Mercury, 3.7
+/// }
+///
+/// let visited = [Planet::Mercury, Planet::Earth].into_iter().collect::<VariantSet<Planet>>();
+/// assert!(visited.contains(&Planet::Earth));
+/// assert!(!visited.contains(&Planet::Mars));
+/// assert_eq!(visited.into_iter().collect::<Vec<_>>(), vec![Planet::Earth, Planet::Mercury]);
+/// ```
+pub struct VariantSet<TIndexed: Indexed> {
+    bits: u64,
+    _indexed: PhantomData<TIndexed>,
+}
+
+impl<TIndexed: Indexed> VariantSet<TIndexed> {
+    const VARIANTS_FIT_IN_BITS: () = assert!(TIndexed::VARIANTS.len() <= u64::BITS as usize,
+        "A VariantSet can't hold enums with more than 64 variants");
+
+    /// Creates a set without any variant.
+    pub const fn new() -> Self {
+        let () = Self::VARIANTS_FIT_IN_BITS;
+        Self { bits: 0, _indexed: PhantomData }
+    }
+
+    /// Creates a set containing every variant.
+    pub const fn all() -> Self {
+        let () = Self::VARIANTS_FIT_IN_BITS;
+        let bits = match TIndexed::VARIANTS.len() {
+            64 => u64::MAX,
+            variants => (1 << variants) - 1,
+        };
+        Self { bits, _indexed: PhantomData }
+    }
+
+    /// Inserts this variant, returning whether it wasn't present before.
+    pub fn insert(&mut self, variant: &TIndexed) -> bool {
+        let was_absent = !self.contains(variant);
+        self.bits |= 1 << variant.discriminant();
+        was_absent
+    }
+
+    /// Removes this variant, returning whether it was present before.
+    pub fn remove(&mut self, variant: &TIndexed) -> bool {
+        let was_present = self.contains(variant);
+        self.bits &= !(1 << variant.discriminant());
+        was_present
+    }
+
+    /// Checks whether this variant is present in the set.
+    pub fn contains(&self, variant: &TIndexed) -> bool {
+        self.bits & (1 << variant.discriminant()) != 0
+    }
+
+    /// Gives the amount of variants present in the set.
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Checks whether the set doesn't contain any variant.
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Gives the bitmask of this set, where each bit is the presence of the variant whose
+    /// discriminant is the bit's position.
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Creates a set from a bitmask where each bit is the presence of the variant whose
+    /// discriminant is the bit's position, giving [None] if it has bits beyond the enum's variants.
+    pub const fn from_bits(bits: u64) -> Option<Self> {
+        if bits & !Self::all().bits != 0 { return None; }
+        Some(Self { bits, _indexed: PhantomData })
+    }
+
+    /// Iterates over the variants present in the set in order of discriminant.
+    pub fn iter(&self) -> VariantSetIter<TIndexed> {
+        VariantSetIter { bits: self.bits, _indexed: PhantomData }
+    }
+}
+
+impl<TIndexed: Indexed> Default for VariantSet<TIndexed> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<TIndexed: Indexed> Clone for VariantSet<TIndexed> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<TIndexed: Indexed> Copy for VariantSet<TIndexed> {}
+
+impl<TIndexed: Indexed> PartialEq for VariantSet<TIndexed> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<TIndexed: Indexed> Eq for VariantSet<TIndexed> {}
+
+impl<TIndexed: Indexed + core::fmt::Debug> core::fmt::Debug for VariantSet<TIndexed> {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<TIndexed: Indexed> IntoIterator for VariantSet<TIndexed> {
+    type Item = TIndexed;
+    type IntoIter = VariantSetIter<TIndexed>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<TIndexed: Indexed> IntoIterator for &VariantSet<TIndexed> {
+    type Item = TIndexed;
+    type IntoIter = VariantSetIter<TIndexed>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<TIndexed: Indexed> FromIterator<TIndexed> for VariantSet<TIndexed> {
+    fn from_iter<TIterator: IntoIterator<Item=TIndexed>>(variants: TIterator) -> Self {
+        let mut set = Self::new();
+        set.extend(variants);
+        set
+    }
+}
+
+impl<TIndexed: Indexed> Extend<TIndexed> for VariantSet<TIndexed> {
+    fn extend<TIterator: IntoIterator<Item=TIndexed>>(&mut self, variants: TIterator) {
+        variants.into_iter().for_each(|variant| { self.insert(&variant); });
+    }
+}
+
+/// Iterator over the variants of a [VariantSet] in order of discriminant.
+pub struct VariantSetIter<TIndexed: Indexed> {
+    bits: u64,
+    _indexed: PhantomData<TIndexed>,
+}
+
+impl<TIndexed: Indexed> Iterator for VariantSetIter<TIndexed> {
+    type Item = TIndexed;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 { return None; }
+        let discriminant = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        TIndexed::from_discriminant_opt(discriminant)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<TIndexed: Indexed> ExactSizeIterator for VariantSetIter<TIndexed> {}
+
+impl<TIndexed: Indexed> FusedIterator for VariantSetIter<TIndexed> {}
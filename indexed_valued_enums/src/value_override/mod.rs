@@ -0,0 +1,45 @@
+use core::any::TypeId;
+use core::cell::RefCell;
+use std::vec::Vec;
+
+use crate::valued_enum::Valued;
+
+/// Values temporarily used in place of [Valued::VALUES] for an enum, stored as a raw pointer so no
+/// bounds are needed on the type of values, as the type of the enum identifies said type.
+struct ValuesOverride {
+    valued_type: TypeId,
+    values: *const (),
+}
+
+std::thread_local! {
+    static VALUES_OVERRIDES: RefCell<Vec<ValuesOverride>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Removes the last override of this thread when dropped, so it's removed even if the function
+/// using it panics.
+struct PopOverrideOnDrop;
+
+impl Drop for PopOverrideOnDrop {
+    fn drop(&mut self) {
+        VALUES_OVERRIDES.with(|overrides| overrides.borrow_mut().pop());
+    }
+}
+
+pub(crate) fn with_values<TValued: Valued, TResult, TFunction: FnOnce() -> TResult>(values: &'static [TValued::Value], function: TFunction) -> TResult {
+    assert_eq!(values.len(), TValued::VARIANT_COUNT,
+               "The overriding values must have the same length as the amount of variants");
+    VALUES_OVERRIDES.with(|overrides| overrides.borrow_mut().push(ValuesOverride {
+        valued_type: TypeId::of::<TValued>(),
+        values: values.as_ptr().cast(),
+    }));
+    let _pop_override = PopOverrideOnDrop;
+    function()
+}
+
+pub(crate) fn overridden_values<TValued: Valued>() -> Option<&'static [TValued::Value]> {
+    VALUES_OVERRIDES.with(|overrides| {
+        let overrides = overrides.borrow();
+        let values = overrides.iter().rev().find(|values_override| values_override.valued_type == TypeId::of::<TValued>())?;
+        Some(unsafe { core::slice::from_raw_parts(values.values.cast::<TValued::Value>(), TValued::VARIANT_COUNT) })
+    })
+}
@@ -1,4 +1,10 @@
-use crate::indexed_enum::{discriminant_internal, Indexed, split_usize_to_isizes};
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use crate::indexed_enum::{discriminant_internal_sized, from_discriminant_opt_internal, Indexed};
+use crate::named_enum::Named;
 
 /// Allows to get a value from an enum's variant, where this enum implements [Indexed], for example,
 /// having the following implementation:
@@ -34,44 +40,109 @@ pub trait Valued: Indexed {
     /// Values each enumeration resolves to, each value must be stored to match it's corresponding
     /// variant, this means it must be sorted in the same order as [Indexed::VARIANTS]
     ///
-    /// This means values must be const
+    /// This means values must be const, so they can't borrow data that doesn't live for 'static,
+    /// if your values do, see [ValuedBorrowed]
     const VALUES: &'static [Self::Value];
 
     /// Gives the value corresponding to this variant, this is an O(1) operation as it just gets the
     /// value as a copy from [Valued::VALUES]
     ///
-    /// The type of [Valued::Value] doesn't need to implement the [Clone] trait as the array is
-    /// treated as a raw pointer whose value is read without cloning through
-    /// [core::ptr::read]
-    ///
-    /// If you just need a reference to the value, use [Valued::value_ref_opt] instead, as it doesn't
-    /// do a read copy.
+    /// The type of [Valued::Value] must implement [Copy], as giving away a bitwise copy of a value
+    /// owning resources would leave two owners of them, if it doesn't, use [Valued::value_ref_opt]
+    /// to get a reference to the value, or [Valued::value_cloned_opt] to get a clone of it.
     ///
     /// Note that if implemented correctly (ensured by using [crate::create_indexed_valued_enum]),
     /// calling this method will always produce [Option::Some(Value)]
-    fn value_opt(&self) -> Option<Self::Value> {
+    fn value_opt(&self) -> Option<Self::Value> where Self::Value: Copy {
+        #[cfg(feature = "value_override")]
+        if let Some(values) = crate::value_override::overridden_values::<Self>() {
+            return values.get(self.discriminant()).copied();
+        }
         value_opt_internal(self)
     }
 
     /// Gives the value corresponding to this variant, this is an O(1) operation as it just gets the
     /// value as a copy from [Valued::VALUES]
-    /// If you just need a reference to the value, use [Valued::value_opt] instead, as it doesn't
-    /// do a read copy.
-    fn value(&self) -> Self::Value {
-        self.value_opt().unwrap()
+    ///
+    /// The type of [Valued::Value] must implement [Copy], if it doesn't, use [Valued::value_ref]
+    /// to get a reference to the value, or [Valued::value_cloned] to get a clone of it.
+    fn value(&self) -> Self::Value where Self::Value: Copy {
+        match self.value_opt() {
+            Some(value) => value,
+            None => panic_missing_value::<Self>(self.discriminant(), None),
+        }
+    }
+
+    /// Gives the value corresponding to this variant, this is an O(1) operation as it just gets the
+    /// value as a copy from [Valued::VALUES]
+    ///
+    /// Unlike [Valued::value], this doesn't panic when the variant doesn't have a value, but it
+    /// gives a [ValueLookupError] describing the variant, which is useful for defensive code
+    /// handling enums that might be corrupt.
+    ///
+    /// As this doesn't require the enum to implement [Named], the error's
+    /// [ValueLookupError::variant_name] is [None], use [ValueLookupError::with_name] to fill it.
+    fn value_res(&self) -> Result<Self::Value, ValueLookupError> where Self::Value: Copy {
+        self.value_opt().ok_or_else(|| ValueLookupError {
+            discriminant: self.discriminant(),
+            variant_name: None,
+            value_count: Self::VALUES.len(),
+        })
+    }
+
+    /// Gives the value of the variant with this discriminant, this is an O(1) operation as it just
+    /// gets the value as a copy from [Valued::VALUES] without getting the variant first, which is
+    /// useful when the discriminant comes from the wire and only its value is needed
+    ///
+    /// The type of [Valued::Value] must implement [Copy], giving [None] if there is no variant
+    /// with such discriminant.
+    fn value_of_opt(discriminant: usize) -> Option<Self::Value> where Self::Value: Copy {
+        #[cfg(feature = "value_override")]
+        if let Some(values) = crate::value_override::overridden_values::<Self>() {
+            return values.get(discriminant).copied();
+        }
+        Self::VALUES.get(discriminant).copied()
+    }
+
+    /// Gives the value of the variant with this discriminant, this is an O(1) operation as it just
+    /// gets the value as a copy from [Valued::VALUES] without getting the variant first
+    ///
+    /// The type of [Valued::Value] must implement [Copy], and this panics if there is no variant
+    /// with such discriminant.
+    fn value_of(discriminant: usize) -> Self::Value where Self::Value: Copy {
+        match Self::value_of_opt(discriminant) {
+            Some(value) => value,
+            None if discriminant < Self::VARIANT_COUNT => panic_missing_value::<Self>(discriminant, None),
+            None => panic!("There is no variant with discriminant {} in {}, as it has {} variants",
+                           discriminant, core::any::type_name::<Self>(), Self::VARIANT_COUNT),
+        }
+    }
+
+    /// Treats the value of this variant as an index into this slice, giving the element at such
+    /// position, like a texture in an atlas, this is an O(1) operation
+    ///
+    /// This requires the type of [Valued::Value] to implement [Copy] and to be convertible into a
+    /// [usize] through [TryInto], like [u8], [u16] or [usize], giving [None] when the value doesn't
+    /// fit in an [usize] or it's out of the slice's bounds.
+    fn value_in<'slice, T>(&self, slice: &'slice [T]) -> Option<&'slice T> where Self::Value: Copy + TryInto<usize> {
+        slice.get(self.value().try_into().ok()?)
     }
 
     /// Gives the value corresponding for a variant of an enum marked with #[repr(usize)] and
     /// implementing the [Valued] trait, this is an O(1) operation as it just gets a reference to the
     /// value as a copy.
     ///
-    /// If you need the value as structure but it doesn't implement clone, use [value_opt_internal]
-    /// instead, as it performs a read copy
+    /// If you need the value itself, use [Valued::value_opt] when it implements [Copy], or
+    /// [Valued::value_cloned_opt] when it implements [Clone]
     ///
     /// Note that if implemented correctly (ensured by the declarative macro
     /// [crate::create_indexed_valued_enum]), calling this method will always produce
     /// [Option::Some(&Value)]
     fn value_ref_opt(&self) -> Option<&'static Self::Value> {
+        #[cfg(feature = "value_override")]
+        if let Some(values) = crate::value_override::overridden_values::<Self>() {
+            return values.get(self.discriminant());
+        }
         value_ref_opt_internal(self)
     }
 
@@ -79,13 +150,101 @@ pub trait Valued: Indexed {
     /// implementing the [Valued] trait, this is an O(1) operation as it just gets a reference to the
     /// value as a copy.
     ///
-    /// If you need the value as structure but it doesn't implement clone, use [value_internal]
-    /// instead, as it performs a read copy
+    /// If you need the value itself, use [Valued::value] when it implements [Copy], or
+    /// [Valued::value_cloned] when it implements [Clone]
     ///
     /// Note that if implemented correctly (ensured by the declarative macro
     /// [crate::create_indexed_valued_enum]), calling this method will never panic
     fn value_ref(&self) -> &'static Self::Value {
-        value_ref_internal(self)
+        match self.value_ref_opt() {
+            Some(value) => value,
+            None => panic_missing_value::<Self>(self.discriminant(), None),
+        }
+    }
+
+    /// Gives a clone of the value corresponding to this variant, this is an O(1) operation as it
+    /// just clones the value from [Valued::VALUES], this is meant for values not implementing
+    /// [Copy], as otherwise [Valued::value_opt] can be used instead.
+    ///
+    /// Note that if implemented correctly (ensured by using [crate::create_indexed_valued_enum]),
+    /// calling this method will always produce [Option::Some(Value)]
+    fn value_cloned_opt(&self) -> Option<Self::Value> where Self::Value: Clone {
+        self.value_ref_opt().cloned()
+    }
+
+    /// Gives a clone of the value corresponding to this variant, this is an O(1) operation as it
+    /// just clones the value from [Valued::VALUES], this is meant for values not implementing
+    /// [Copy], as otherwise [Valued::value] can be used instead.
+    fn value_cloned(&self) -> Self::Value where Self::Value: Clone {
+        self.value_ref().clone()
+    }
+
+    /// Runs this function while the values of this enum are overridden by these values in the
+    /// current thread, meaning [Valued::value_opt], [Valued::value], [Valued::value_ref_opt] and
+    /// [Valued::value_ref] give these values instead of the ones in [Valued::VALUES], this is
+    /// useful to test your code against different tables of values without redefining your enum.
+    ///
+    /// Overrides can be nested, in which case the innermost one is used, and they are removed once
+    /// the function ends, even if it panics.
+    ///
+    /// This is a development and testing facility which requires the feature 'value_override', as
+    /// it makes every lookup check for overrides, note it doesn't affect the const functions of
+    /// the 'Delegators' feature nor the const internal functions.
+    ///
+    /// This panics if the amount of values differs from [Indexed::VARIANT_COUNT].
+    #[cfg(feature = "value_override")]
+    fn with_values<TResult, TFunction: FnOnce() -> TResult>(values: &'static [Self::Value], function: TFunction) -> TResult {
+        crate::value_override::with_values::<Self, TResult, TFunction>(values, function)
+    }
+
+    /// Gives the [core::any::TypeId] of [Valued::Value], which allows tooling to group enums by the
+    /// type of their values, or to check two enums have the same type of values at runtime.
+    ///
+    /// This requires the feature 'std'.
+    #[cfg(feature = "std")]
+    fn value_type_id() -> core::any::TypeId where Self::Value: 'static {
+        core::any::TypeId::of::<Self::Value>()
+    }
+
+    /// Gives every value of this enum as a slice, where the index of each value equals the
+    /// discriminant of the variant it belongs to, this allows processing values in bulk, like
+    /// `values_slice().iter().max()`, without copying them or requiring them to implement [Copy].
+    ///
+    /// Note this is always [Valued::VALUES], so it isn't affected by the overrides of the feature
+    /// 'value_override'.
+    fn values_slice() -> &'static [Self::Value] {
+        Self::VALUES
+    }
+
+    /// Iterates over the values of every variant in order of discriminant, these values are given
+    /// as copies from [Valued::VALUES], so the type of [Valued::Value] must implement [Copy]
+    ///
+    /// If your values don't implement [Copy], use [Valued::values_ref] instead.
+    fn values() -> ValuesIter<Self> where Self::Value: Copy {
+        ValuesIter { discriminants: 0..Self::VALUES.len(), _valued: PhantomData }
+    }
+
+    /// Iterates over references to the values of every variant in order of discriminant, as these
+    /// are references to [Valued::VALUES], it doesn't require [Valued::Value] to implement [Copy]
+    /// nor [Clone].
+    fn values_ref() -> core::slice::Iter<'static, Self::Value> {
+        Self::VALUES.iter()
+    }
+
+    /// Iterates over every variant along it's value in order of discriminant, like [Valued::values],
+    /// the type of [Valued::Value] must implement [Copy] as values are given as copies
+    ///
+    /// If your values don't implement [Copy], use [Valued::iter_pairs_ref] instead.
+    fn iter_pairs() -> PairsIter<Self> where Self::Value: Copy {
+        PairsIter { discriminants: 0..Self::VALUES.len(), _valued: PhantomData }
+    }
+
+    /// Iterates over every variant along a reference to it's value in order of discriminant, as
+    /// variants are read from [Indexed::VARIANTS] through [core::ptr::read] and values are
+    /// referenced from [Valued::VALUES], it requires neither the enum nor [Valued::Value] to
+    /// implement [Copy] or [Clone].
+    fn iter_pairs_ref() -> PairsRefIter<Self> {
+        PairsRefIter { discriminants: 0..Self::VALUES.len(), _valued: PhantomData }
     }
 
     /// Gives variant corresponding to a value, this is an O(n) operation as it does so by comparing
@@ -98,6 +257,63 @@ pub trait Valued: Indexed {
         Self::from_discriminant_opt(discriminant?)
     }
 
+    /// Gives variant corresponding to a value, this is an O(n) operation as it does so by comparing
+    /// every single value contained in [Valued::VALUES], giving a [NoVariantForValue] error if no
+    /// variant has such value
+    fn try_value_to_variant(value: &Self::Value) -> Result<Self, NoVariantForValue> where Self::Value: PartialEq {
+        Self::value_to_variant_opt(value).ok_or(NoVariantForValue)
+    }
+
+    /// Gives the first variant whose value fulfills this predicate, this is an O(n) operation as it
+    /// does so by applying the predicate on every single value contained in [Valued::VALUES]
+    ///
+    /// Unlike [Valued::value_to_variant_opt], this doesn't require the type of value to implement
+    /// [PartialEq], which is useful to find variants by just one of the fields of their values
+    fn value_to_variant_by<TPredicate: Fn(&Self::Value) -> bool>(predicate: TPredicate) -> Option<Self> {
+        let discriminant = Self::VALUES.iter().position(predicate);
+        Self::from_discriminant_opt(discriminant?)
+    }
+
+    /// Gives the variant whose value is this string, this is an O(n) operation as it does so by
+    /// comparing every single value contained in [Valued::VALUES] as a [str]
+    ///
+    /// Unlike [Valued::value_to_variant_opt], this doesn't require building a value to look for,
+    /// like a String when values are strings, as values are compared after being borrowed as [str]
+    fn value_to_variant_str(query: &str) -> Option<Self> where Self::Value: Borrow<str> {
+        Self::value_to_variant_by(|value| value.borrow() == query)
+    }
+
+    /// Iterates over every variant whose value is equal to this value in order of discriminant,
+    /// unlike [Valued::value_to_variant_opt], this doesn't stop at the first variant, which is
+    /// useful when several variants share the same value. This doesn't allocate, as it compares
+    /// each value contained in [Valued::VALUES] while iterating
+    fn value_to_variants_all(value: &Self::Value) -> VariantsWithValueIter<'_, Self> where Self::Value: PartialEq {
+        VariantsWithValueIter { value, discriminants: 0..Self::VALUES.len() }
+    }
+
+    /// Gives the variant with the highest discriminant whose value is lower or equal to this value,
+    /// when values are sorted in ascending order, this is the variant this value falls into, like a
+    /// floor function, which is useful for brackets or tiers. This is an O(n) operation as it does
+    /// so by comparing every single value contained in [Valued::VALUES]
+    ///
+    /// If every value is greater than this value, it returns [None]
+    fn value_floor_variant(value: &Self::Value) -> Option<Self> where Self::Value: PartialOrd {
+        let discriminant = Self::VALUES.iter().rposition(|variant_value| variant_value <= value);
+        Self::from_discriminant_opt(discriminant?)
+    }
+
+    /// Gives the variant with the lowest discriminant whose value is greater or equal to this value,
+    /// when values are sorted in ascending order, this is the first variant this value doesn't
+    /// surpass, like a ceiling function, being the counterpart of [Valued::value_floor_variant].
+    /// This is an O(n) operation as it does so by comparing every single value contained in
+    /// [Valued::VALUES]
+    ///
+    /// If every value is lower than this value, it returns [None]
+    fn value_ceil_variant(value: &Self::Value) -> Option<Self> where Self::Value: PartialOrd {
+        let discriminant = Self::VALUES.iter().position(|variant_value| variant_value >= value);
+        Self::from_discriminant_opt(discriminant?)
+    }
+
     /// Gives variant corresponding to a value, this is an O(n) operation as it does so by comparing
     /// every single value contained in [Valued::VALUES]
     fn value_to_variant(value: &Self::Value) -> Self where Self::Value: PartialEq {
@@ -105,52 +321,367 @@ pub trait Valued: Indexed {
     }
 }
 
+/// Allows to get a value borrowing data that lives for 'a from an enum's variant, which is meant
+/// for enums parameterized by a lifetime, like an enum whose variants borrow slices of an input
+/// buffer.
+///
+/// Such enums can't implement [Indexed] nor [Valued], as [Indexed::VARIANTS] and
+/// [Valued::VALUES] are constants referenced for 'static, and a reference living for 'static
+/// can't point to data that only lives for 'a, so these enums have to implement this trait by hand,
+/// for example:
+///
+/// ```rust
+/// use indexed_valued_enums::valued_enum::ValuedBorrowed;
+///
+/// enum Field<'a> { Name(&'a str), Email(&'a str) }
+///
+/// impl<'a> ValuedBorrowed<'a> for Field<'a> {
+///     type Value = &'a str;
+///
+///     fn value_borrowed(&self) -> &'a str {
+///         match self { Field::Name(value) | Field::Email(value) => value }
+///     }
+/// }
+///
+/// let buffer = String::from("name=Ferris");
+/// let field = Field::Name(&buffer[5..]);
+/// assert_eq!(field.value_borrowed(), "Ferris");
+/// ```
+///
+/// Every enum implementing [Valued] implements this trait too, giving a reference to it's value,
+/// so generic code bound on [ValuedBorrowed] accepts both kinds of enums.
+pub trait ValuedBorrowed<'a> {
+    /// Type of the values the enumeration resolves to, which may borrow data living for 'a
+    type Value: 'a;
+
+    /// Gives the value corresponding to this variant
+    fn value_borrowed(&self) -> Self::Value;
+}
+
+impl<'a, TValued: Valued> ValuedBorrowed<'a> for TValued where TValued::Value: 'a {
+    type Value = &'a TValued::Value;
+
+    /// Gives a reference to the value corresponding to this variant, just like [Valued::value_ref]
+    fn value_borrowed(&self) -> &'a TValued::Value {
+        self.value_ref()
+    }
+}
+
+/// Variant of an enum implementing [Valued] whose value can be overridden at runtime, giving the
+/// overriding value when there is one, and the variant's value in [Valued::VALUES] otherwise, this
+/// allows using the values of the enum as defaults while patching some of them at runtime:
+///
+/// ```rust
+/// use indexed_valued_enums::{create_indexed_valued_enum, valued_enum::ValuedOverride};
+///
+/// create_indexed_valued_enum! {
+///     #[derive(PartialEq, Debug)]
+///     enum Limit valued as u32;
+///     Requests, 100,
+///     Connections, 10
+/// }
+///
+/// let mut limit = ValuedOverride::new(Limit::Requests);
+/// assert_eq!(*limit, 100);
+/// limit.set_value(500);
+/// assert_eq!(*limit, 500);
+/// ```
+///
+/// Unlike the overrides of the feature 'value_override', this doesn't require said feature, and
+/// it only affects this wrapper rather than every lookup of the enum's values.
+#[derive(Clone, Debug)]
+pub struct ValuedOverride<TValued: Valued> {
+    variant: TValued,
+    value: Option<TValued::Value>,
+}
+
+impl<TValued: Valued> ValuedOverride<TValued> {
+    /// Wraps this variant without overriding it's value.
+    pub const fn new(variant: TValued) -> Self {
+        Self { variant, value: None }
+    }
+
+    /// Wraps this variant overriding it's value with this one.
+    pub const fn with_value(variant: TValued, value: TValued::Value) -> Self {
+        Self { variant, value: Some(value) }
+    }
+
+    /// Gets the wrapped variant.
+    pub const fn variant(&self) -> &TValued {
+        &self.variant
+    }
+
+    /// Consumes this wrapper giving back the wrapped variant.
+    pub fn into_variant(self) -> TValued {
+        self.variant
+    }
+
+    /// Checks whether the value of the variant is being overridden.
+    pub const fn is_overridden(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Overrides the value of the variant, giving back the previous overriding value if any.
+    pub fn set_value(&mut self, value: TValued::Value) -> Option<TValued::Value> {
+        self.value.replace(value)
+    }
+
+    /// Removes the overriding value, so the variant's value in [Valued::VALUES] is given again,
+    /// giving back the removed value if any.
+    pub fn clear_value(&mut self) -> Option<TValued::Value> {
+        self.value.take()
+    }
+
+    /// Gives a reference to the overriding value if there is one, or to the variant's value in
+    /// [Valued::VALUES] otherwise.
+    pub fn value_ref(&self) -> &TValued::Value {
+        match &self.value {
+            Some(value) => value,
+            None => self.variant.value_ref(),
+        }
+    }
+
+    /// Gives a copy of the overriding value if there is one, or of the variant's value in
+    /// [Valued::VALUES] otherwise.
+    pub fn value(&self) -> TValued::Value where TValued::Value: Copy {
+        *self.value_ref()
+    }
+}
+
+impl<TValued: Valued> core::ops::Deref for ValuedOverride<TValued> {
+    type Target = TValued::Value;
+
+    fn deref(&self) -> &Self::Target {
+        self.value_ref()
+    }
+}
+
+/// Error produced when a variant doesn't have a value, as [Valued::VALUES] has less values than
+/// [Indexed::VARIANTS] has variants, which doesn't happen when using this crate's macros.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ValueLookupError {
+    /// Discriminant of the variant without a value.
+    pub discriminant: usize,
+    /// Name of the variant without a value, filled through [ValueLookupError::with_name].
+    pub variant_name: Option<&'static str>,
+    /// Amount of values of the enum, that is, the length of [Valued::VALUES].
+    pub value_count: usize,
+}
+
+impl ValueLookupError {
+    /// Fills [ValueLookupError::variant_name] with the name of the variant in
+    /// [Named::VARIANT_NAMES], like
+    /// ```variant.value_res().map_err(ValueLookupError::with_name::<MyEnum>)```
+    pub fn with_name<NamedType: Named>(self) -> Self {
+        Self { variant_name: NamedType::VARIANT_NAMES.get(self.discriminant).copied(), ..self }
+    }
+}
+
+impl core::fmt::Display for ValueLookupError {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.variant_name {
+            Some(variant_name) => write!(formatter, "The variant {} with discriminant {} doesn't have a value, as there are only {} values",
+                                         variant_name, self.discriminant, self.value_count),
+            None => write!(formatter, "The variant with discriminant {} doesn't have a value, as there are only {} values",
+                           self.discriminant, self.value_count),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValueLookupError {}
+
+/// Error produced when looking for the variant of a value that no variant has, it doesn't keep the
+/// value it looked for, so it can be produced for any type of value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NoVariantForValue;
+
+impl core::fmt::Display for NoVariantForValue {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("There is no variant with such value")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoVariantForValue {}
+
+/// Iterator over the values of every variant of an enum implementing [Valued] in order of
+/// discriminant, created through [Valued::values].
+pub struct ValuesIter<TValued: Valued> {
+    discriminants: Range<usize>,
+    _valued: PhantomData<TValued>,
+}
+
+impl<TValued: Valued> ValuesIter<TValued> where TValued::Value: Copy {
+    fn read_value(discriminant: usize) -> TValued::Value {
+        TValued::VALUES[discriminant]
+    }
+}
+
+impl<TValued: Valued> Iterator for ValuesIter<TValued> where TValued::Value: Copy {
+    type Item = TValued::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.discriminants.next().map(Self::read_value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.discriminants.size_hint()
+    }
+}
+
+impl<TValued: Valued> DoubleEndedIterator for ValuesIter<TValued> where TValued::Value: Copy {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.discriminants.next_back().map(Self::read_value)
+    }
+}
+
+impl<TValued: Valued> ExactSizeIterator for ValuesIter<TValued> where TValued::Value: Copy {}
+
+impl<TValued: Valued> FusedIterator for ValuesIter<TValued> where TValued::Value: Copy {}
+
+/// Iterator over every variant of an enum implementing [Valued] whose value is equal to a given
+/// value in order of discriminant, created through [Valued::value_to_variants_all].
+pub struct VariantsWithValueIter<'value, TValued: Valued> where TValued::Value: PartialEq {
+    value: &'value TValued::Value,
+    discriminants: Range<usize>,
+}
+
+impl<'value, TValued: Valued> Iterator for VariantsWithValueIter<'value, TValued> where TValued::Value: PartialEq {
+    type Item = TValued;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.value;
+        let discriminant = self.discriminants.find(|discriminant| TValued::VALUES[*discriminant].eq(value))?;
+        from_discriminant_opt_internal(discriminant)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.discriminants.len()))
+    }
+}
+
+impl<'value, TValued: Valued> DoubleEndedIterator for VariantsWithValueIter<'value, TValued> where TValued::Value: PartialEq {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.value;
+        let discriminant = self.discriminants.rfind(|discriminant| TValued::VALUES[*discriminant].eq(value))?;
+        from_discriminant_opt_internal(discriminant)
+    }
+}
+
+impl<'value, TValued: Valued> FusedIterator for VariantsWithValueIter<'value, TValued> where TValued::Value: PartialEq {}
+
+/// Iterator over every variant of an enum implementing [Valued] along it's value in order of
+/// discriminant, created through [Valued::iter_pairs].
+pub struct PairsIter<TValued: Valued> {
+    discriminants: Range<usize>,
+    _valued: PhantomData<TValued>,
+}
+
+impl<TValued: Valued> PairsIter<TValued> where TValued::Value: Copy {
+    fn read_pair(discriminant: usize) -> Option<(TValued, TValued::Value)> {
+        Some((from_discriminant_opt_internal(discriminant)?, ValuesIter::<TValued>::read_value(discriminant)))
+    }
+}
+
+impl<TValued: Valued> Iterator for PairsIter<TValued> where TValued::Value: Copy {
+    type Item = (TValued, TValued::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::read_pair(self.discriminants.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.discriminants.size_hint()
+    }
+}
+
+impl<TValued: Valued> DoubleEndedIterator for PairsIter<TValued> where TValued::Value: Copy {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Self::read_pair(self.discriminants.next_back()?)
+    }
+}
+
+impl<TValued: Valued> ExactSizeIterator for PairsIter<TValued> where TValued::Value: Copy {}
+
+impl<TValued: Valued> FusedIterator for PairsIter<TValued> where TValued::Value: Copy {}
+
+/// Iterator over every variant of an enum implementing [Valued] along a reference to it's value in
+/// order of discriminant, created through [Valued::iter_pairs_ref].
+pub struct PairsRefIter<TValued: Valued> {
+    discriminants: Range<usize>,
+    _valued: PhantomData<TValued>,
+}
+
+impl<TValued: Valued> PairsRefIter<TValued> {
+    fn read_pair(discriminant: usize) -> Option<(TValued, &'static TValued::Value)> {
+        Some((from_discriminant_opt_internal(discriminant)?, &TValued::VALUES[discriminant]))
+    }
+}
+
+impl<TValued: Valued> Iterator for PairsRefIter<TValued> {
+    type Item = (TValued, &'static TValued::Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::read_pair(self.discriminants.next()?)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.discriminants.size_hint()
+    }
+}
+
+impl<TValued: Valued> DoubleEndedIterator for PairsRefIter<TValued> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Self::read_pair(self.discriminants.next_back()?)
+    }
+}
+
+impl<TValued: Valued> ExactSizeIterator for PairsRefIter<TValued> {}
+
+impl<TValued: Valued> FusedIterator for PairsRefIter<TValued> {}
+
 /// Gives the value corresponding for a variant of an enum marked with #[repr(usize)], this is an
 /// O(1) operation as it just gets the value as a copy from [Valued::VALUES]
 ///
-/// The type of [Valued::Value] doesn't need to implement the [Clone] trait as the array is
-/// treated as a raw pointer whose value is read without cloning through
-/// [core::ptr::read]
+/// The type of [Valued::Value] must implement [Copy], as giving away a bitwise copy of a value
+/// owning resources would leave two owners of them.
 ///
 /// Note that if implemented correctly (ensured by the declarative macro
 /// [crate::create_indexed_valued_enum]), calling this method will always produce
 /// [Option::Some(Value)]
-pub const fn value_opt_internal<ValuedType: Valued>(variant: &ValuedType) -> Option<ValuedType::Value> {
-    let discriminant = discriminant_internal(variant);
-    if discriminant >= ValuedType::VARIANTS.len() { return None; }
-    let (first_offset, second_offset, third_offset) = split_usize_to_isizes(discriminant);
-    Some(unsafe { ValuedType::VALUES.as_ptr().offset(first_offset).offset(second_offset).offset(third_offset).read() })
+pub const fn value_opt_internal<ValuedType: Valued>(variant: &ValuedType) -> Option<ValuedType::Value> where ValuedType::Value: Copy {
+    let discriminant = discriminant_internal_sized(variant);
+    if discriminant >= ValuedType::VALUES.len() { return None; }
+    Some(ValuedType::VALUES[discriminant])
 }
 
 /// Gives the value corresponding for a variant of an enum marked with #[repr(usize)], this is an
 /// O(1) operation as it just gets the value as a copy from [Valued::VALUES]
 ///
-/// The type of [Valued::Value] doesn't need to implement the [Clone] trait as the array is
-/// treated as a raw pointer whose value is read without cloning through
-/// [core::ptr::read]
+/// The type of [Valued::Value] must implement [Copy], as giving away a bitwise copy of a value
+/// owning resources would leave two owners of them.
 ///
 /// Note that if implemented correctly (ensured by the declarative macro
 /// [crate::create_indexed_valued_enum]), this method should never panic.
-pub const fn value_internal<ValuedType: Valued>(variant: &ValuedType) -> ValuedType::Value {
-    let discriminant = discriminant_internal(variant);
-    if discriminant >= ValuedType::VARIANTS.len() { panic!("Tried to get a variant's value whose index is larger than the amount of Variants") }
-    let (first_offset, second_offset, third_offset) = split_usize_to_isizes(discriminant);
-    unsafe { ValuedType::VALUES.as_ptr().offset(first_offset).offset(second_offset).offset(third_offset).read() }
+pub const fn value_internal<ValuedType: Valued>(variant: &ValuedType) -> ValuedType::Value where ValuedType::Value: Copy {
+    let discriminant = discriminant_internal_sized(variant);
+    if discriminant >= ValuedType::VALUES.len() { panic!("Tried to get the value of a variant without one, as there are less values in Valued::VALUES than variants in Indexed::VARIANTS") }
+    ValuedType::VALUES[discriminant]
 }
 
 /// Gives the value corresponding for a variant of an enum marked with #[repr(usize)] and
 /// implementing the [Valued] trait, this is an O(1) operation as it just gets a reference to the
 /// value as a copy.
 ///
-/// If you need the value as structure but it doesn't implement clone, use [value_opt_internal]
-/// instead, as it performs a read copy
+/// If you need the value itself and it implements [Copy], use [value_opt_internal] instead
 ///
 /// Note that if implemented correctly (ensured by the declarative macro
 /// [crate::create_indexed_valued_enum]), calling this method will always produce
 /// [Option::Some(&Value)]
 pub const fn value_ref_opt_internal<ValuedType: Valued>(variant: &ValuedType) -> Option<&'static ValuedType::Value> {
-    let discriminant = discriminant_internal(variant);
-    if discriminant >= ValuedType::VARIANTS.len() { return None; }
+    let discriminant = discriminant_internal_sized(variant);
+    if discriminant >= ValuedType::VALUES.len() { return None; }
     Some(&ValuedType::VALUES[discriminant])
 }
 
@@ -158,14 +689,37 @@ pub const fn value_ref_opt_internal<ValuedType: Valued>(variant: &ValuedType) ->
 /// implementing the [Valued] trait, this is an O(1) operation as it just gets a reference to the
 /// value as a copy.
 ///
-/// If you need the value as structure but it doesn't implement clone, use [value_internal]
-/// instead, as it performs a read copy
+/// If you need the value itself and it implements [Copy], use [value_internal] instead
 ///
 /// Note that if implemented correctly (ensured by the declarative macro
 /// [crate::create_indexed_valued_enum]), calling this method will never panic
 pub const fn value_ref_internal<ValuedType: Valued>(variant: &ValuedType) -> &'static ValuedType::Value {
-    let discriminant = discriminant_internal(variant);
-    if discriminant >= ValuedType::VARIANTS.len() { panic!("Tried to get a variant's value whose index is larger than the amount of Variants") }
+    let discriminant = discriminant_internal_sized(variant);
+    if discriminant >= ValuedType::VALUES.len() { panic!("Tried to get the value of a variant without one, as there are less values in Valued::VALUES than variants in Indexed::VARIANTS") }
     &ValuedType::VALUES[discriminant]
 }
 
+/// Panics telling the variant of this discriminant has no value, including its name from
+/// [Named::VARIANT_NAMES], which only happens when [Valued::VALUES] has less values than
+/// [Indexed::VARIANTS] has variants.
+///
+/// This is used by the implementations of [Valued::value] and [Valued::value_ref] made by the
+/// macros of this crate, as the default ones can't name the variant.
+#[cold]
+#[track_caller]
+pub fn panic_missing_named_value<NamedType: Valued + Named>(discriminant: usize) -> ! {
+    panic_missing_value::<NamedType>(discriminant, NamedType::VARIANT_NAMES.get(discriminant).copied())
+}
+
+/// Panics telling the variant of this discriminant has no value, which only happens when
+/// [Valued::VALUES] has less values than [Indexed::VARIANTS] has variants.
+#[cold]
+#[track_caller]
+fn panic_missing_value<ValuedType: Valued>(discriminant: usize, variant_name: Option<&'static str>) -> ! {
+    match variant_name {
+        Some(variant_name) => panic!("The variant {} with discriminant {} of {} doesn't have a value, as it has {} variants but {} values",
+                                     variant_name, discriminant, core::any::type_name::<ValuedType>(), ValuedType::VARIANT_COUNT, ValuedType::VALUES.len()),
+        None => panic!("The variant with discriminant {} of {} doesn't have a value, as it has {} variants but {} values",
+                       discriminant, core::any::type_name::<ValuedType>(), ValuedType::VARIANT_COUNT, ValuedType::VALUES.len()),
+    }
+}
@@ -0,0 +1,14 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(u8)]
+#[auto_copy]
+enum Shape {
+    #[value(0)]
+    Point,
+    #[value(1)]
+    #[variant_initialize_uses(1)]
+    Circle(u8),
+}
+
+fn main() {}
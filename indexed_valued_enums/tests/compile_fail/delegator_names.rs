@@ -0,0 +1,36 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(u16)]
+#[enum_valued_features(Delegators)]
+#[delegator_names(value = "val", price = "cost")]
+enum Unknown {
+    #[value(0)]
+    Free,
+    #[value(20)]
+    Pro,
+}
+
+#[derive(Valued)]
+#[enum_valued_as(u16)]
+#[enum_valued_features(Delegators)]
+#[delegator_names(value = "first")]
+enum Colliding {
+    #[value(0)]
+    Free,
+    #[value(20)]
+    Pro,
+}
+
+#[derive(Valued)]
+#[enum_valued_as(u16)]
+#[enum_valued_features(Delegators)]
+#[delegator_names(value = "not a name")]
+enum Invalid {
+    #[value(0)]
+    Free,
+    #[value(20)]
+    Pro,
+}
+
+fn main() {}
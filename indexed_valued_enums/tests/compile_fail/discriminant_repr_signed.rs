@@ -0,0 +1,21 @@
+use indexed_valued_enums::create_indexed_valued_enum;
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+create_indexed_valued_enum! {
+    ##[discriminant_repr(i8)]
+    enum SignedNumber valued as u8;
+    First, 1,
+    Second, 2
+}
+
+#[derive(Valued)]
+#[enum_valued_as(u8)]
+#[discriminant_repr(u128)]
+enum WideNumber {
+    #[value(1)]
+    First,
+    #[value(2)]
+    Second,
+}
+
+fn main() {}
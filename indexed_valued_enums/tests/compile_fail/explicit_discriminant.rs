@@ -0,0 +1,12 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(u8)]
+enum Number {
+    #[value(0)]
+    Zero,
+    #[value(5)]
+    Five = 5,
+}
+
+fn main() {}
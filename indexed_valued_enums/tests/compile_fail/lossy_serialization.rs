@@ -0,0 +1,14 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Serialize)]
+enum Signal {
+    #[value(0)]
+    Idle,
+    #[value(1)]
+    #[variant_initialize_uses(50)]
+    Busy(u8),
+}
+
+fn main() {}
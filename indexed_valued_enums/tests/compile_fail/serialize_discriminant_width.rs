@@ -0,0 +1,8 @@
+include!("../three_hundred_variants.in");
+
+three_hundred_variants! {
+    ##[serialize_discriminant_as(u8)]
+    enum ThreeHundred valued as u16;
+}
+
+fn main() {}
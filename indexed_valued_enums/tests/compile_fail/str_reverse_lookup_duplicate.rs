@@ -0,0 +1,15 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(&'static str)]
+#[enum_valued_features(StrReverseLookup)]
+enum Extension {
+    #[value("jpg")]
+    Jpg,
+    #[value("jpeg")]
+    Jpeg,
+    #[value("jpg")]
+    Jfif,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+#[derive(Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Default)]
+enum Number {
+    #[default]
+    #[value(0)]
+    Zero,
+    #[default]
+    #[value(1)]
+    One,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use indexed_valued_enums::create_indexed_valued_enum;
+
+create_indexed_valued_enum! {
+    ##[features((Delegators value: val, value_owned: owned))]
+    enum Tier valued as u16;
+    Free, 0,
+    Pro, 20
+}
+
+fn main() {}
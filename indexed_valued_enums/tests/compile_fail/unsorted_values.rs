@@ -0,0 +1,11 @@
+use indexed_valued_enums::create_indexed_valued_enum;
+
+create_indexed_valued_enum! {
+    ##[features(SortedValues)]
+    enum Unsorted valued as u8;
+    First, 10,
+    Second, 20,
+    Third, 15
+}
+
+fn main() {}
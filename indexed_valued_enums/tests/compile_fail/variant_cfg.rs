@@ -0,0 +1,19 @@
+use indexed_valued_enums::{create_indexed_enum, create_indexed_valued_enum};
+
+create_indexed_valued_enum! {
+    enum Release valued as u16;
+    Legacy, 1,
+    #[cfg(any())]
+    Hidden, 2,
+    Stable, 3
+}
+
+create_indexed_enum! {
+    enum Stage;
+    Draft,
+    #[cfg(any())]
+    Review,
+    Published
+}
+
+fn main() {}
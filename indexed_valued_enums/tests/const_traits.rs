@@ -0,0 +1,53 @@
+#![feature(const_trait_impl)]
+
+use indexed_valued_enums::const_traits::{ConstIndexed, ConstValued};
+use indexed_valued_enums::create_indexed_valued_enum;
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(ConstTraitImpls)]
+    enum Planet valued as f32;
+    Earth, 9.807,
+    Mars, 3.71,
+    Mercury, 3.7
+}
+
+#[derive(PartialEq, Debug, Valued)]
+#[enum_valued_as(&'static str)]
+#[const_trait_impls]
+enum Greeting {
+    #[value("Hello")]
+    Hello,
+    #[value("Bye")]
+    Bye,
+}
+
+const fn next_discriminant<T: [const] ConstIndexed>(variant: &T) -> usize {
+    ConstIndexed::discriminant(variant) + 1
+}
+
+#[test]
+fn test_const_trait_impls() {
+    const MARS: usize = Planet::Mars.discriminant();
+    const MERCURY: Planet = Planet::from_discriminant(2);
+    const EARTH_GRAVITY: f32 = Planet::Earth.value();
+    const LAST: Option<Planet> = Planet::last();
+    assert_eq!(MARS, 1);
+    assert_eq!(MERCURY, Planet::Mercury);
+    assert_eq!(EARTH_GRAVITY, 9.807);
+    assert_eq!(LAST, Some(Planet::Mercury));
+
+    const AFTER_MARS: usize = next_discriminant(&Planet::Mars);
+    assert_eq!(AFTER_MARS, 2);
+}
+
+#[test]
+fn test_const_trait_impls_derive() {
+    const BYE: &str = Greeting::Bye.value();
+    const FIRST: Option<Greeting> = Greeting::from_discriminant_opt(0);
+    const HELLO: Option<&'static &'static str> = Greeting::Hello.value_ref_opt();
+    assert_eq!(BYE, "Bye");
+    assert_eq!(FIRST, Some(Greeting::Hello));
+    assert_eq!(HELLO, Some(&"Hello"));
+}
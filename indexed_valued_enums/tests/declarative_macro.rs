@@ -19,7 +19,6 @@ struct NumberDescription {
 #[test]
 fn test() {
     assert_eq!(Number::Zero.discriminant(), 0);
-    assert_eq!(Number::First.value().description, "First position");
     assert_eq!(Number::First.value_ref().description, "First position");
     assert_eq!(Number::First.index, 1);
     assert_eq!(Number::Second.clone(), Number::Second);
@@ -27,4 +26,406 @@ fn test() {
         &NumberDescription { description: "Third position", index: 3 }));
     assert!(Number::value_to_variant_opt(
         &NumberDescription { description: "Fourth position", index: 4 }).is_none());
-}
\ No newline at end of file
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(Delegators, Display, IntoDiscriminant, AsRefValue, Names)]
+    enum Position valued as NumberDescription;
+    Zero, NumberDescription { description: "Zero position", index: 0 },
+    First, NumberDescription { description: "First position", index: 1 },
+    Second, NumberDescription { description: "Second position", index: 2 },
+    Third, NumberDescription { description: "Third position", index: 3 }
+}
+
+#[test]
+fn test_as_ref_value() {
+    fn describe<T: AsRef<NumberDescription>>(number: T) -> &'static str {
+        number.as_ref().description
+    }
+
+    assert_eq!(describe(&Position::First), "First position");
+    assert_eq!(describe(Position::Third), "Third position");
+    assert!(core::ptr::eq(Position::Second.as_ref(), Position::Second.value_ref()));
+}
+
+#[test]
+fn test_into_discriminant() {
+    assert_eq!(usize::from(Position::Second), 2);
+    assert_eq!(usize::from(&Position::First), 1);
+    let discriminant: usize = Position::Third.into();
+    assert_eq!(Position::from_discriminant(discriminant), Position::Third);
+}
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(BorrowValue, HashByValue, TryFromDiscriminant)]
+    enum Color valued as &'static str;
+    Red, "red",
+    Green, "green",
+    Blue, "blue"
+}
+
+#[test]
+fn test_borrow_value() {
+    let mut hex_codes = std::collections::HashMap::new();
+    hex_codes.insert(Color::Red, "#FF0000");
+    hex_codes.insert(Color::Blue, "#0000FF");
+    assert_eq!(hex_codes.get(&"red"), Some(&"#FF0000"));
+    assert_eq!(hex_codes.get(&"blue"), Some(&"#0000FF"));
+    assert_eq!(hex_codes.get(&"green"), None);
+}
+
+#[test]
+fn test_try_from_discriminant() {
+    use indexed_valued_enums::indexed_enum::DiscriminantOutOfRange;
+
+    fn read_colors(discriminants: &[usize]) -> Result<Vec<Color>, DiscriminantOutOfRange> {
+        discriminants.iter().map(|discriminant| Color::try_from(*discriminant)).collect()
+    }
+
+    assert_eq!(Color::try_from(1), Ok(Color::Green));
+    assert_eq!(read_colors(&[2, 0]), Ok(vec![Color::Blue, Color::Red]));
+    assert_eq!(Color::try_from(3), Err(DiscriminantOutOfRange { discriminant: 3, variant_count: 3 }));
+    assert_eq!(read_colors(&[0, 5]), Err(DiscriminantOutOfRange { discriminant: 5, variant_count: 3 }));
+}
+
+include!("planet_variants.in");
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(Delegators)]
+    enum PlanetGravity valued as f32;
+    variants from planet_variants
+}
+
+create_indexed_valued_enum! {
+    pub enum PublicPlanetGravity valued as f32;
+    variants from planet_variants
+}
+
+#[test]
+fn test_variants_from_macro() {
+    assert_eq!(PlanetGravity::Mars.value(), 3.71);
+    assert_eq!(PlanetGravity::from_discriminant(2), PlanetGravity::Mercury);
+    assert_eq!(indexed_valued_enums::valued_enum::Valued::value_ref(&PublicPlanetGravity::Earth), &9.807);
+}
+
+create_indexed_valued_enum! {
+    ##[features(SumValues, ProductValues)]
+    enum Component valued as u32;
+    Engine, 4,
+    Wheels, 5,
+    Seats, 3
+}
+
+#[test]
+fn test_sum_and_product_values() {
+    assert_eq!(Component::sum_of_all_values(), 12);
+    assert_eq!(Component::product_of_all_values(), 60);
+}
+
+#[test]
+fn test_iter() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    assert_eq!(Number::iter().len(), 4);
+    assert_eq!(Number::iter().collect::<Vec<_>>(), vec![Number::Zero, Number::First, Number::Second, Number::Third]);
+    assert_eq!(Number::iter().rev().collect::<Vec<_>>(), vec![Number::Third, Number::Second, Number::First, Number::Zero]);
+}
+
+include!("thousand_variants.in");
+
+#[test]
+fn test_sorted_values() {
+    use indexed_valued_enums::{indexed_enum::Indexed, valued_enum::Valued};
+
+    assert_eq!(Thousand::value_to_variant_sorted_opt(&0), Some(Thousand::V0));
+    assert_eq!(Thousand::value_to_variant_sorted(&1500), Thousand::V500);
+    assert_eq!(Thousand::value_to_variant_sorted_opt(&2997), Some(Thousand::V999));
+    assert_eq!(Thousand::value_to_variant_sorted_opt(&1501), None);
+    assert_eq!(Thousand::value_to_variant_sorted_opt(&3000), None);
+    for value in 0..3000 {
+        assert_eq!(Thousand::value_to_variant_sorted_opt(&value), Thousand::value_to_variant_opt(&value));
+    }
+    assert!(Thousand::iter().all(|variant| Thousand::value_to_variant_sorted(&variant.value()) == variant));
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SortedValuesUnchecked)]
+    enum Element valued as &'static str;
+    Carbon, "C",
+    Hydrogen, "H",
+    Nitrogen, "N",
+    Oxygen, "O"
+}
+
+#[test]
+fn test_sorted_values_unchecked() {
+    assert_eq!(Element::value_to_variant_sorted_opt(&"N"), Some(Element::Nitrogen));
+    assert_eq!(Element::value_to_variant_sorted(&"C"), Element::Carbon);
+    assert_eq!(Element::value_to_variant_sorted_opt(&"He"), None);
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(OrdByValueThenDiscriminant)]
+    enum Priority valued as u8;
+    Urgent, 0,
+    Normal, 5,
+    Critical, 0,
+    Low, 9
+}
+
+#[test]
+fn test_ord_by_value_then_discriminant() {
+    let mut priorities = vec![Priority::Low, Priority::Critical, Priority::Normal, Priority::Urgent];
+    priorities.sort();
+    assert_eq!(priorities, vec![Priority::Urgent, Priority::Critical, Priority::Normal, Priority::Low]);
+    assert!(Priority::Urgent < Priority::Critical);
+    assert_eq!(Priority::Normal.cmp(&Priority::Normal), core::cmp::Ordering::Equal);
+}
+
+create_indexed_valued_enum! {
+    ##[features(DisplayNameValue)]
+    enum PlanetDisplay valued as f32;
+    Earth, 9.807,
+    Mars, 3.71
+}
+
+#[test]
+fn test_display_name_value() {
+    assert_eq!(PlanetDisplay::Mars.to_string(), "Mars = 3.71");
+    assert_eq!(format!("{}", PlanetDisplay::Earth), "Earth = 9.807");
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    enum Alias valued as &'static str;
+    Original, "orig",
+    Shortcut, "dup",
+    Unique, "unique",
+    Nickname, "dup"
+}
+
+#[test]
+fn test_value_to_variants_all() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let duplicated = Alias::value_to_variants_all(&"dup").collect::<Vec<_>>();
+    assert_eq!(duplicated, vec![Alias::Shortcut, Alias::Nickname]);
+    assert_eq!(Alias::value_to_variants_all(&"dup").next_back(), Some(Alias::Nickname));
+    assert_eq!(Alias::value_to_variants_all(&"unique").collect::<Vec<_>>(), vec![Alias::Unique]);
+    assert_eq!(Alias::value_to_variants_all(&"missing").next(), None);
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    enum Release valued as u16;
+    /// First release, kept for compatibility
+    #[deprecated]
+    Legacy, 1,
+    Stable, 2,
+    #[doc(hidden)]
+    Testing, 3
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_variant_metadata() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(Release::VARIANTS, &[Release::Legacy, Release::Stable, Release::Testing]);
+    assert_eq!(Release::Testing.value(), 3);
+    assert_eq!(Release::value_to_variant(&1), Release::Legacy);
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    enum Instruction valued as &'static str;
+    Halt, "halt",
+    Jump(u8, u16) = (2, 3), "jump",
+    Move { x: i32, y: i32 } = { x: 1, y: -2 }, "move"
+}
+
+#[test]
+fn test_field_variants() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    const _: () = assert!(!Instruction::IS_FIELD_LESS);
+    assert_eq!(Instruction::VARIANTS, &[Instruction::Halt, Instruction::Jump(2, 3), Instruction::Move { x: 1, y: -2 }]);
+    assert_eq!(Instruction::Jump(7, 7).discriminant(), 1);
+    assert_eq!(Instruction::Move { x: 0, y: 0 }.value(), "move");
+    assert_eq!(Instruction::from_discriminant(2), Instruction::Move { x: 1, y: -2 });
+    assert_eq!(Instruction::value_to_variant(&"jump"), Instruction::Jump(2, 3));
+}
+
+indexed_valued_enums::create_indexed_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(Clone, Delegators)]
+    enum Weekday;
+    Monday,
+    Tuesday,
+    Wednesday
+}
+
+#[test]
+fn test_indexed_enum() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    const _: () = assert!(Weekday::IS_FIELD_LESS);
+    assert_eq!(Weekday::VARIANTS, &[Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday]);
+    assert_eq!(Weekday::Tuesday.discriminant(), 1);
+    assert_eq!(Weekday::from_discriminant(2), Weekday::Wednesday);
+    assert_eq!(Weekday::from_discriminant_opt(3), None);
+    assert_eq!(Weekday::Monday.clone(), Weekday::Monday);
+    assert_eq!(Weekday::iter().map(|weekday| weekday.discriminant()).collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(Delegators)]
+    enum Ordinal valued as &'static str;
+    First, "1st",
+    Second, "2nd"
+}
+
+#[test]
+fn test_const_delegators() {
+    const FIRST: &str = Ordinal::First.value();
+    const SECOND_REF: &&str = Ordinal::Second.value_ref();
+    const SECOND: Ordinal = Ordinal::from_discriminant(1);
+    const SECOND_DISCRIMINANT: usize = SECOND.discriminant();
+    assert_eq!(FIRST, "1st");
+    assert_eq!(*SECOND_REF, "2nd");
+    assert_eq!(SECOND, Ordinal::Second);
+    assert_eq!(SECOND_DISCRIMINANT, 1);
+    assert_eq!(Ordinal::First.value_ref_opt(), Some(&"1st"));
+}
+
+create_indexed_valued_enum! {
+    ##[features(IterValues)]
+    enum Coin valued as u16;
+    Penny, 1,
+    Nickel, 5,
+    Dime, 10
+}
+
+#[test]
+fn test_iter_values() {
+    assert_eq!(Coin::iter_values().copied().collect::<Vec<_>>(), vec![1, 5, 10]);
+    assert_eq!(Coin::iter_values().next_back(), Some(&10));
+    assert_eq!(Coin::iter_values().len(), 3);
+}
+
+#[test]
+fn test_value_to_variant_str() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let query = String::from("unique");
+    assert_eq!(Alias::value_to_variant_str(&query), Some(Alias::Unique));
+    assert_eq!(Alias::value_to_variant_str("dup"), Some(Alias::Shortcut));
+    assert_eq!(Alias::value_to_variant_str("missing"), None);
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(Delegators)]
+    ##[discriminant_repr(u16)]
+    enum SmallPlanet valued as f32;
+    Earth, 9.807,
+    Mars, 3.71,
+    Mercury, 3.7
+}
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features((Delegators value: val, label: tag))]
+    enum Tier valued as u16;
+    Free, 0,
+    Pro, 20
+}
+
+impl Tier {
+    fn value(&self) -> &'static str {
+        "tier"
+    }
+
+    fn label(&self) -> String {
+        format!("tier {}", self.discriminant())
+    }
+}
+
+#[test]
+fn test_partially_renamed_delegators() {
+    const PRO_PRICE: u16 = Tier::Pro.val();
+    assert_eq!(PRO_PRICE, 20);
+    assert_eq!(Tier::Pro.tag(), (1, "Pro"));
+    assert_eq!(Tier::Pro.value(), "tier");
+    assert_eq!(Tier::Pro.label(), "tier 1");
+    assert_eq!(Tier::Free.neighbors(), (None, Some(Tier::Pro)));
+    assert_eq!(Tier::first(), Some(Tier::Free));
+}
+
+#[test]
+fn test_discriminant_repr() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(core::mem::size_of::<SmallPlanet>(), core::mem::size_of::<u16>());
+    assert_eq!(<SmallPlanet as Indexed>::DISCRIMINANT_SIZE, 2);
+    assert_eq!(Indexed::discriminant(&SmallPlanet::Mercury), 2);
+    assert_eq!(SmallPlanet::Mars.discriminant(), 1);
+    assert_eq!(Valued::value(&SmallPlanet::Mars), 3.71);
+    assert_eq!(SmallPlanet::Mercury.value(), 3.7);
+}
+
+indexed_valued_enums::assert_indexed_valued_invariants!(Alias);
+
+indexed_valued_enums::assert_indexed_valued_invariants!(Coin, unique values);
+
+indexed_valued_enums::assert_indexed_valued_invariants!(Instruction, unique values);
+
+create_indexed_valued_enum! {
+    #[allow(non_camel_case_types)]
+    #[derive(PartialEq, Debug)]
+    ##[features(Display, FromStr, Names)]
+    enum Keyword valued as u8;
+    r#type, 0,
+    r#match, 1,
+    Plain, 2
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Position::Third), "Third");
+    assert_eq!(format!("{:>7}", Position::Zero), "   Zero");
+    assert_eq!(Keyword::r#type.to_string(), "type");
+    assert_eq!(Keyword::r#match.to_string(), "match");
+    assert_eq!(Keyword::Plain.to_string(), "Plain");
+}
+
+#[test]
+fn test_names() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    const THIRD_NAME: &str = Position::Third.variant_name();
+    assert_eq!(THIRD_NAME, "Third");
+    assert_eq!(Position::VARIANT_NAMES, &["Zero", "First", "Second", "Third"]);
+    assert_eq!(Position::VARIANT_NAMES.len(), Position::VARIANT_COUNT);
+    assert_eq!(Keyword::r#type.variant_name(), "type");
+    assert_eq!(Keyword::VARIANT_NAMES, &["type", "match", "Plain"]);
+}
+
+#[test]
+fn test_from_str() {
+    use indexed_valued_enums::named_enum::ParseVariantError;
+
+    assert_eq!("Plain".parse::<Keyword>(), Ok(Keyword::Plain));
+    assert_eq!("type".parse::<Keyword>(), Ok(Keyword::r#type));
+    assert_eq!(Keyword::r#match.to_string().parse::<Keyword>(), Ok(Keyword::r#match));
+    assert_eq!("plain".parse::<Keyword>(), Err(ParseVariantError));
+    assert_eq!("Unknown".parse::<Keyword>(), Err(ParseVariantError));
+}
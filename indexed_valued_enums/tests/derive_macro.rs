@@ -78,7 +78,6 @@ enum NumberCustom {
 #[test]
 fn test_custom_type() {
     assert_eq!(NumberCustom::Zero.discriminant(), 0);
-    assert_eq!(NumberCustom::First.value().num, 1);
     assert_eq!(NumberCustom::First.value_ref().num, 1);
     assert_eq!(NumberCustom::Ten.num, 10);
     assert_eq!(NumberCustom::Ten.name, "Ten");
@@ -87,11 +86,33 @@ fn test_custom_type() {
     assert!(NumberCustom::value_to_variant_opt(&MyType { num: 4, name: "Fourth" }).is_none());
 }
 
+#[test]
+fn test_value_ref_points_to_values() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let first: &'static MyType = NumberCustom::First.value_ref();
+    assert!(first == &<NumberCustom as Valued>::VALUES[1]);
+    assert!(core::ptr::eq(first, NumberCustom::First.value_ref_opt().unwrap()));
+    assert!(core::ptr::eq(first, Valued::value_ref(&NumberCustom::First)));
+}
+
 #[test]
 fn test_custom_constr() {
     assert!(NumberValueConstr::value_to_variant_opt(&4).is_none());
 }
 
+const _: () = assert!(NumberValueConstr::eq_discriminant(
+    &NumberValueConstr::First(1, 2), &NumberValueConstr::First(3, 4)));
+const _: () = assert!(!NumberValueConstr::eq_discriminant(
+    &NumberValueConstr::Zero(1, 2), &NumberValueConstr::First(1, 2)));
+
+#[test]
+fn test_eq_discriminant() {
+    let second = NumberValueConstr::Second { a: 1, b: 2 };
+    assert!(NumberValueConstr::eq_discriminant(&second, &NumberValueConstr::Second { a: 3, b: 4 }));
+    assert!(!NumberValueConstr::eq_discriminant(&second, &NumberValueConstr::Third { c: 1, d: 2 }));
+}
+
 #[derive(Clone, Debug, PartialEq, Valued)]
 #[enum_valued_features(Delegators, ValueToVariantDelegators, DerefToValue)]
 #[enum_valued_as(u8)]
@@ -156,7 +177,1254 @@ fn example_test() {
     assert_eq!(Planets::Mercury.discriminant(), 2);
 
     //Value mechanics
-    assert_eq!(Planets::Earth.value().radius, 6357.0);
+    assert_eq!(Planets::Earth.value_ref().radius, 6357.0);
     assert_eq!(Planets::Mars.gravity, 3.71);
     assert_eq!(Planets::Mercury, Planets::value_to_variant(&Planet { radius: 2439.7, gravity: 3.7 }));
-}
\ No newline at end of file
+}
+#[derive(PartialEq, Debug, Valued)]
+#[enum_valued_as(f32)]
+#[enum_valued_features(Delegators, Registry)]
+enum PlanetRadius {
+    #[value(6357.0)]
+    Earth,
+    #[value(3389.5)]
+    Mars,
+    #[value(2439.7)]
+    Mercury,
+}
+
+#[test]
+fn test_registry() {
+    const REGISTRY: &[(&str, usize)] = PlanetRadius::REGISTRY;
+    assert_eq!(REGISTRY, &[("Earth", 0), ("Mars", 1), ("Mercury", 2)]);
+
+    let mut registered = std::collections::HashMap::new();
+    for (name, discriminant) in PlanetRadius::REGISTRY {
+        registered.insert(*name, *discriminant);
+    }
+    assert_eq!(PlanetRadius::from_discriminant(registered["Mars"]), PlanetRadius::Mars);
+}
+
+#[test]
+fn test_enum_map() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::map::EnumMap;
+
+    let mut weights: EnumMap<Planets, f32, 3> = Planets::remap(|planet| planet.gravity * 10.0);
+    assert_eq!(weights[Planets::Mars], 37.1);
+    weights[Planets::Mars] = 0.0;
+    assert_eq!(weights.get(&Planets::Mars), &0.0);
+    assert_eq!(weights.into_array(), [98.07, 0.0, 37.0]);
+
+    let names = EnumMap::<Planets, &str, 3>::from_array(["Earth", "Mars", "Mercury"]);
+    assert!(names.iter().all(|(planet, name)| format!("{planet:?}") == *name));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_indexed_vec() {
+    use indexed_valued_enums::indexed_vec::IndexedVec;
+
+    let mut visits = IndexedVec::<Planets, u32>::new(0);
+    visits[Planets::Mars] = 3;
+    visits[Planets::Mercury] += 1;
+    assert_eq!(visits.get(&Planets::Mars), &3);
+    assert_eq!(visits.as_slice(), &[0, 3, 1]);
+
+    let names = IndexedVec::<Planets, String>::from_fn(|planet| format!("{planet:?}"));
+    assert_eq!(names[Planets::Earth], "Earth");
+    assert!(names.iter().all(|(planet, name)| format!("{planet:?}") == *name));
+    assert!(IndexedVec::<Planets, u32>::from_vec(vec![1, 2]).is_err());
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(Planet)]
+#[enum_valued_features(Delegators)]
+#[enum_value_by_ref]
+enum PlanetsByRef {
+    #[value(Planet{ radius: 6357.0, gravity: 9.807 })]
+    Earth,
+    #[value(Planet{ radius: 3389.5, gravity: 3.71 })]
+    Mars,
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Delegators)]
+#[enum_value_by_ref]
+enum NumberValueByRef {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+}
+
+#[test]
+fn test_value_by_ref() {
+    let earth: &'static Planet = PlanetsByRef::Earth.value();
+    assert_eq!(earth.radius, 6357.0);
+    assert_eq!(PlanetsByRef::Mars.value_opt().map(|mars| mars.gravity), Some(3.71));
+    assert_eq!(PlanetsByRef::from_discriminant(1), PlanetsByRef::Mars);
+    assert_eq!(NumberValueByRef::First.value(), &1);
+    assert_eq!(NumberValueByRef::First.value_owned(), 1);
+    assert_eq!(NumberValueByRef::Zero.value_owned_opt(), Some(0));
+}
+
+#[test]
+fn test_variant_set() {
+    use indexed_valued_enums::set::VariantSet;
+
+    let set = vec![NumberValue::Third, NumberValue::First, NumberValue::Third]
+        .into_iter()
+        .collect::<VariantSet<NumberValue>>();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&NumberValue::First));
+    assert!(!set.contains(&NumberValue::Zero));
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![NumberValue::First, NumberValue::Third]);
+    assert_eq!(set.into_iter().collect::<VariantSet<_>>(), set);
+    assert_eq!(VariantSet::<NumberValue>::all().into_iter().len(), 4);
+}
+
+#[test]
+fn test_values() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let numbers = NumberValue::values().collect::<Vec<_>>();
+    assert_eq!(numbers, vec![0, 1, 2, 3]);
+    assert_eq!(NumberValue::values().len(), NumberValue::VARIANTS.len());
+
+    let radii = Planets::values_ref().map(|planet| planet.radius).collect::<Vec<_>>();
+    assert_eq!(radii, vec![6357.0, 3389.5, 2439.7]);
+    assert_eq!(radii.len(), Planets::VARIANTS.len());
+
+    let gravities = Planets::values_ref().map(|planet| planet.gravity).collect::<Vec<_>>();
+    assert_eq!(gravities, vec![9.807, 3.71, 3.7]);
+    assert_eq!(Planets::values_ref().next_back().map(|planet| planet.radius), Some(2439.7));
+}
+
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Delegators, DerefToValue)]
+#[delegator_names(value = "val", discriminant = "idx")]
+enum RenamedDelegators {
+    #[value(10)]
+    First,
+    #[value(20)]
+    Second,
+}
+
+impl RenamedDelegators {
+    fn value(&self) -> &'static str {
+        match self {
+            RenamedDelegators::First => "first",
+            RenamedDelegators::Second => "second",
+        }
+    }
+}
+
+#[test]
+fn test_renamed_delegators() {
+    const SECOND_VALUE: u8 = RenamedDelegators::Second.val();
+    assert_eq!(SECOND_VALUE, 20);
+    assert_eq!(RenamedDelegators::First.idx(), 0);
+    assert_eq!(RenamedDelegators::First.value(), "first");
+    assert_eq!(RenamedDelegators::from_discriminant(1), RenamedDelegators::Second);
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Delegators)]
+#[delegator_names(label = "tag", neighbors = "adjacent", dispatch_value = "dispatch")]
+enum LabelledTask {
+    #[value(1)]
+    Build,
+    #[value(2)]
+    Deploy,
+}
+
+impl LabelledTask {
+    fn label(&self) -> &'static str {
+        "task"
+    }
+
+    fn neighbors(&self) -> usize {
+        1
+    }
+}
+
+#[test]
+fn test_renamed_label_and_neighbors_delegators() {
+    const DEPLOY_TAG: (usize, &str) = LabelledTask::Deploy.tag();
+    assert_eq!(DEPLOY_TAG, (1, "Deploy"));
+    assert_eq!(LabelledTask::Build.adjacent(), (None, Some(LabelledTask::Deploy)));
+    assert_eq!(LabelledTask::Deploy.dispatch(&[|| "building", || "deploying"]), "deploying");
+    assert_eq!(LabelledTask::Build.label(), "task");
+    assert_eq!(LabelledTask::Build.neighbors(), 1);
+}
+
+#[test]
+fn test_iter_pairs() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let pairs = NumberValue::iter_pairs().collect::<Vec<_>>();
+    assert_eq!(pairs, vec![(NumberValue::Zero, 0), (NumberValue::First, 1), (NumberValue::Second, 2), (NumberValue::Third, 3)]);
+    assert!(NumberValue::iter_pairs().all(|(variant, value)| variant.value() == value));
+    assert_eq!(NumberValue::iter_pairs().next_back(), Some((NumberValue::Third, 3)));
+
+    let radii = Planets::iter_pairs_ref().map(|(planet, value)| (planet, value.radius)).collect::<Vec<_>>();
+    assert_eq!(radii, vec![(Planets::Earth, 6357.0), (Planets::Mars, 3389.5), (Planets::Mercury, 2439.7)]);
+    assert_eq!(Planets::iter_pairs_ref().len(), 3);
+    assert_eq!(Planets::iter_pairs_ref().next_back().map(|(planet, _)| planet), Some(Planets::Mercury));
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(f32)]
+enum TaxBracket {
+    #[value(0.0)]
+    Exempt,
+    #[value(12000.0)]
+    Low,
+    #[value(50000.0)]
+    High,
+}
+
+#[test]
+fn test_value_floor_variant() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(TaxBracket::value_floor_variant(&-1.0), None);
+    assert_eq!(TaxBracket::value_floor_variant(&0.0), Some(TaxBracket::Exempt));
+    assert_eq!(TaxBracket::value_floor_variant(&11999.9), Some(TaxBracket::Exempt));
+    assert_eq!(TaxBracket::value_floor_variant(&12000.0), Some(TaxBracket::Low));
+    assert_eq!(TaxBracket::value_floor_variant(&1e9), Some(TaxBracket::High));
+    assert_eq!(NumberValue::value_floor_variant(&2), Some(NumberValue::Second));
+}
+
+#[test]
+fn test_value_ceil_variant() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(TaxBracket::value_ceil_variant(&-1.0), Some(TaxBracket::Exempt));
+    assert_eq!(TaxBracket::value_ceil_variant(&0.1), Some(TaxBracket::Low));
+    assert_eq!(TaxBracket::value_ceil_variant(&12000.0), Some(TaxBracket::Low));
+    assert_eq!(TaxBracket::value_ceil_variant(&50000.0), Some(TaxBracket::High));
+    assert_eq!(TaxBracket::value_ceil_variant(&50000.1), None);
+}
+
+#[test]
+fn test_variant_count() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    let counters = [0u32; NumberValue::VARIANT_COUNT];
+    assert_eq!(counters.len(), NumberValue::VARIANTS.len());
+    assert_eq!(Planets::VARIANT_COUNT, 3);
+    let weights = Planets::remap::<_, _, { Planets::VARIANT_COUNT }>(|planet| planet.value_ref().gravity);
+    assert_eq!(weights[Planets::Mars], 3.71);
+}
+
+#[test]
+fn test_next_and_previous_variant() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    assert_eq!(NumberValue::Zero.next_variant(), Some(NumberValue::First));
+    assert_eq!(NumberValue::First.next_variant(), Some(NumberValue::Second));
+    assert_eq!(NumberValue::Second.next_variant(), Some(NumberValue::Third));
+    assert_eq!(NumberValue::Third.next_variant(), None);
+
+    assert_eq!(NumberValue::Zero.previous_variant(), None);
+    assert_eq!(NumberValue::First.previous_variant(), Some(NumberValue::Zero));
+    assert_eq!(NumberValue::Second.previous_variant(), Some(NumberValue::First));
+    assert_eq!(NumberValue::Third.previous_variant(), Some(NumberValue::Second));
+}
+
+#[test]
+fn test_wrapping_navigation() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    assert_eq!(NumberValue::Third.wrapping_next(), NumberValue::Zero);
+    assert_eq!(NumberValue::Zero.wrapping_prev(), NumberValue::Third);
+    assert_eq!(NumberValue::First.wrapping_next(), NumberValue::Second);
+    assert_eq!(NumberValue::First.wrapping_prev(), NumberValue::Zero);
+}
+
+#[test]
+fn test_adjacent_pairs() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    assert_eq!(NumberValue::adjacent_pairs().collect::<Vec<_>>(), vec![
+        (NumberValue::Zero, NumberValue::First),
+        (NumberValue::First, NumberValue::Second),
+        (NumberValue::Second, NumberValue::Third),
+    ]);
+    assert_eq!(Planets::adjacent_pairs().nth(1), Some((Planets::Mars, Planets::Mercury)));
+
+    #[derive(Debug, PartialEq)]
+    enum Single { Only }
+    impl Indexed for Single {
+        const VARIANTS: &'static [Self] = &[Single::Only];
+    }
+    assert_eq!(Single::adjacent_pairs().next(), None);
+}
+
+#[test]
+fn test_label() {
+    use indexed_valued_enums::named_enum::Named;
+
+    const LABELS: [(usize, &str); 3] = [Planets::Earth.label(), Planets::Mars.label(), Planets::Mercury.label()];
+    assert_eq!(LABELS, [(0, "Earth"), (1, "Mars"), (2, "Mercury")]);
+    assert_eq!(NumberValue::Second.label(), (2, "Second"));
+    assert_eq!(Named::label(&Keyword::Type), (0, "Type"));
+}
+
+#[test]
+fn test_neighbors() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    const SECOND_NEIGHBORS: (Option<NumberValue>, Option<NumberValue>) = NumberValue::Second.neighbors();
+    assert_eq!(SECOND_NEIGHBORS, (Some(NumberValue::First), Some(NumberValue::Third)));
+    assert_eq!(NumberValue::Zero.neighbors(), (None, Some(NumberValue::First)));
+    assert_eq!(NumberValue::Third.neighbors(), (Some(NumberValue::Second), None));
+    assert_eq!(Indexed::neighbors(&Planets::Earth), (None, Some(Planets::Mars)));
+    assert_eq!(Indexed::neighbors(&Planets::Mercury), (Some(Planets::Mars), None));
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+enum Keyword {
+    #[value(0)]
+    r#Type,
+    #[value(1)]
+    Match,
+}
+
+#[test]
+fn test_variant_names() {
+    use indexed_valued_enums::named_enum::Named;
+
+    assert_eq!(NumberValue::Third.variant_name(), "Third");
+    assert_eq!(Keyword::Type.variant_name(), "Type");
+    assert_eq!(Keyword::VARIANT_NAMES, &["Type", "Match"]);
+    assert_eq!(Keyword::from_variant_name_opt("Match"), Some(Keyword::Match));
+    assert_eq!(Keyword::from_variant_name_opt("match"), None);
+    assert_eq!(Keyword::from_variant_name_ignore_case_opt("TYPE"), Some(Keyword::Type));
+}
+
+enum NoVariants {}
+
+impl indexed_valued_enums::indexed_enum::Indexed for NoVariants {
+    const VARIANTS: &'static [Self] = &[];
+}
+
+#[derive(Debug)]
+#[repr(usize)]
+enum MissingValue {
+    Valued,
+    Unvalued,
+}
+
+impl indexed_valued_enums::indexed_enum::Indexed for MissingValue {
+    const VARIANTS: &'static [Self] = &[MissingValue::Valued, MissingValue::Unvalued];
+}
+
+impl indexed_valued_enums::valued_enum::Valued for MissingValue {
+    type Value = u8;
+    const VALUES: &'static [Self::Value] = &[1];
+}
+
+impl indexed_valued_enums::named_enum::Named for MissingValue {
+    const VARIANT_NAMES: &'static [&'static str] = &["Valued", "Unvalued"];
+}
+
+#[test]
+#[should_panic(expected = "MissingValue doesn't have a value, as it has 2 variants but 1 values")]
+fn test_missing_value_panic_message() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(MissingValue::Valued.value(), 1);
+    assert!(MissingValue::Unvalued.value_opt().is_none());
+    MissingValue::Unvalued.value_ref();
+}
+
+#[test]
+#[should_panic(expected = "The variant Unvalued with discriminant 1 of")]
+fn test_missing_named_value_panic_message() {
+    indexed_valued_enums::valued_enum::panic_missing_named_value::<MissingValue>(1);
+}
+
+#[test]
+fn test_value_res() {
+    use indexed_valued_enums::valued_enum::{Valued, ValueLookupError};
+
+    assert_eq!(MissingValue::Valued.value_res(), Ok(1));
+    let error = MissingValue::Unvalued.value_res().unwrap_err();
+    assert_eq!(error, ValueLookupError { discriminant: 1, variant_name: None, value_count: 1 });
+    assert_eq!(error.to_string(), "The variant with discriminant 1 doesn't have a value, as there are only 1 values");
+    let error = error.with_name::<MissingValue>();
+    assert_eq!(error.variant_name, Some("Unvalued"));
+    assert_eq!(error.to_string(), "The variant Unvalued with discriminant 1 doesn't have a value, as there are only 1 values");
+}
+
+#[test]
+fn test_first_and_last() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    const FIRST: Option<NumberValue> = NumberValue::first();
+    const LAST: Option<NumberValue> = NumberValue::last();
+    assert_eq!(FIRST, Some(NumberValue::Zero));
+    assert_eq!(LAST, Some(NumberValue::Third));
+    assert_eq!(<TaxBracket as Indexed>::first(), Some(TaxBracket::Exempt));
+    assert_eq!(<TaxBracket as Indexed>::last(), Some(TaxBracket::High));
+    assert!(NoVariants::first().is_none());
+    assert!(NoVariants::last().is_none());
+}
+
+#[test]
+fn test_is_field_less() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    const _: () = assert!(NumberValue::IS_FIELD_LESS);
+    const _: () = assert!(!NumberValueConstr::IS_FIELD_LESS);
+    const _: () = assert!(!NumberComplex::IS_FIELD_LESS);
+    const _: () = assert!(!NoVariants::IS_FIELD_LESS);
+    let field_less = [Planets::IS_FIELD_LESS, NumberComplex::IS_FIELD_LESS];
+    assert_eq!(field_less, [true, false]);
+}
+
+#[derive(Valued)]
+#[enum_valued_as(f32)]
+#[enum_valued_features(DebugNameAndValue)]
+enum PlanetDebug {
+    #[value(9.807)]
+    Earth,
+    #[value(3.71)]
+    Mars,
+    #[value(1.0)]
+    #[variant_initialize_uses(5)]
+    Moon(u8),
+}
+
+#[test]
+fn test_debug_name_and_value() {
+    assert_eq!(format!("{:?}", PlanetDebug::Mars), "Mars(3.71)");
+    assert_eq!(format!("{:?}", PlanetDebug::Earth), "Earth(9.807)");
+    let moon = PlanetDebug::Moon(7);
+    assert_eq!(format!("{:?}", moon), "Moon(1.0)");
+    assert!(matches!(moon, PlanetDebug::Moon(7)));
+}
+
+#[test]
+fn test_try_from_discriminant() {
+    use indexed_valued_enums::indexed_enum::{DiscriminantOutOfRange, Indexed};
+
+    assert_eq!(NumberValue::try_from_discriminant(2), Ok(NumberValue::Second));
+    let error = NumberValue::try_from_discriminant(7).unwrap_err();
+    assert_eq!(error, DiscriminantOutOfRange { discriminant: 7, variant_count: 4 });
+    let message = error.to_string();
+    assert!(message.contains('7') && message.contains('4'), "{message}");
+    #[cfg(feature = "std")]
+    assert!(std::error::Error::source(&error).is_none());
+}
+
+#[derive(Clone, Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(TryFromValue, IntoValue)]
+enum Digit {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+    #[value(2)]
+    Second,
+    #[value(3)]
+    Third,
+}
+
+#[test]
+fn test_value_conversions() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::NoVariantForValue;
+
+    assert_eq!(Digit::try_from(2), Ok(Digit::Second));
+    assert_eq!(Digit::try_from(4), Err(NoVariantForValue));
+    let value: u8 = Digit::Third.into();
+    assert_eq!(value, 3);
+    assert_eq!(u8::from(Digit::Zero), 0);
+    assert!(Digit::iter().all(|number| Digit::try_from(u8::from(number.clone())) == Ok(number)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_value_type_id() {
+    use indexed_valued_enums::valued_enum::Valued;
+    use std::any::TypeId;
+
+    assert_eq!(NumberValue::value_type_id(), TypeId::of::<u8>());
+    assert_eq!(NumberValue::value_type_id(), NumberValueDefaulted::value_type_id());
+    assert_ne!(NumberValue::value_type_id(), Planets::value_type_id());
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(&'static str)]
+#[enum_valued_features(NumFromPrimitive, NumToPrimitive)]
+enum Opcode {
+    #[value("nop")]
+    Nop,
+    #[value("load")]
+    Load,
+    #[value("store")]
+    Store,
+}
+
+#[test]
+fn test_num_primitive() {
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    assert_eq!(Opcode::from_u64(2), Some(Opcode::Store));
+    assert_eq!(Opcode::from_i64(0), Some(Opcode::Nop));
+    assert_eq!(Opcode::from_u64(3), None);
+    assert_eq!(Opcode::from_i64(-1), None);
+    assert_eq!(Opcode::Store.to_u64(), Some(2));
+    assert_eq!(Opcode::Load.to_i32(), Some(1));
+}
+
+#[test]
+fn test_dispatch_value() {
+    fn zero() -> &'static str { "zero" }
+    fn one() -> &'static str { "one" }
+    fn two() -> &'static str { "two" }
+    fn three() -> &'static str { "three" }
+
+    let handlers: [fn() -> &'static str; 4] = [zero, one, two, three];
+    assert_eq!(NumberValue::Second.dispatch_value(&handlers), "two");
+    assert_eq!(NumberValue::Zero.dispatch_value(&handlers), "zero");
+    assert_eq!(Planets::Mars.dispatch_value(&[|| 9.807, || 3.71, || 3.7]), 3.71);
+}
+
+#[test]
+fn test_value_of() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(NumberValue::value_of(2), 2);
+    assert_eq!(NumberValue::value_of_opt(3), Some(3));
+    assert_eq!(NumberValue::value_of_opt(4), None);
+    assert!(std::panic::catch_unwind(|| NumberValue::value_of(4)).is_err());
+}
+
+enum Header<'a> {
+    Host(&'a str),
+    Accept(&'a str),
+}
+
+impl<'a> indexed_valued_enums::valued_enum::ValuedBorrowed<'a> for Header<'a> {
+    type Value = &'a str;
+
+    fn value_borrowed(&self) -> &'a str {
+        match self {
+            Header::Host(value) | Header::Accept(value) => value,
+        }
+    }
+}
+
+#[test]
+fn test_valued_borrowed() {
+    use indexed_valued_enums::valued_enum::ValuedBorrowed;
+
+    fn value_len<'a, T: ValuedBorrowed<'a, Value = &'a str>>(variant: &T) -> usize {
+        variant.value_borrowed().len()
+    }
+
+    let request = String::from("Host: example.org\nAccept: text/plain");
+    let (host_line, accept_line) = request.split_once('\n').unwrap();
+    let headers = [Header::Host(&host_line[6..]), Header::Accept(&accept_line[8..])];
+    assert_eq!(headers[0].value_borrowed(), "example.org");
+    assert_eq!(headers[1].value_borrowed(), "text/plain");
+    assert_eq!(value_len(&headers[1]), 10);
+
+    assert_eq!(Planets::Mars.value_borrowed().gravity, 3.71);
+    assert_eq!(*ValuedBorrowed::value_borrowed(&NumberValue::Second), 2);
+}
+
+#[test]
+fn test_valued_override() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::ValuedOverride;
+
+    let mut numbers = NumberValue::iter().map(ValuedOverride::new).collect::<Vec<_>>();
+    assert_eq!(numbers[2].set_value(20), None);
+    assert_eq!(numbers.iter().map(|number| number.value()).collect::<Vec<_>>(), vec![0, 1, 20, 3]);
+    assert!(numbers[2].is_overridden());
+    assert!(!numbers[3].is_overridden());
+    assert_eq!(*numbers[2], 20);
+    assert_eq!(numbers[2].variant(), &NumberValue::Second);
+
+    assert_eq!(numbers[2].clear_value(), Some(20));
+    assert_eq!(*numbers[2], 2);
+
+    let mars = ValuedOverride::with_value(Planets::Mars, Planet { radius: 3389.5, gravity: 0.0 });
+    assert_eq!(mars.gravity, 0.0);
+    assert_eq!(mars.into_variant(), Planets::Mars);
+}
+
+#[test]
+fn test_values_slice() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(NumberValue::values_slice().iter().sum::<u8>(), 6);
+    assert_eq!(NumberValue::values_slice()[NumberValue::Third.discriminant()], 3);
+    assert_eq!(Planets::values_slice().iter().map(|planet| planet.radius).fold(0.0, f32::max), 6357.0);
+}
+
+#[test]
+fn test_value_ref_through_trait() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    fn value_of_generic<T: Valued>(variant: &T) -> &T::Value {
+        variant.value_ref()
+    }
+
+    fn value_of_generic_opt<T: Valued>(variant: &T) -> Option<&T::Value> {
+        variant.value_ref_opt()
+    }
+
+    assert_eq!(value_of_generic(&Planets::Mars).gravity, 3.71);
+    assert_eq!(value_of_generic_opt(&Planets::Mercury).map(|planet| planet.radius), Some(2439.7));
+    assert_eq!(*value_of_generic(&NumberValue::Third), 3);
+    assert_eq!(value_of_generic_opt(&NumberValue::Zero), Some(&0));
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(&'static str)]
+#[enum_valued_features(StrReverseLookup)]
+enum MimeType {
+    #[value("text/plain")]
+    Text,
+    #[value("application/json")]
+    Json,
+    #[value("image/png")]
+    Png,
+    #[value("audio/ogg")]
+    Ogg,
+}
+
+#[test]
+fn test_str_reverse_lookup() {
+    assert_eq!(MimeType::SORTED_STR_VALUES, &[("application/json", 1), ("audio/ogg", 3), ("image/png", 2), ("text/plain", 0)]);
+    assert_eq!(MimeType::value_to_variant_str_opt("image/png"), Some(MimeType::Png));
+    assert_eq!(MimeType::value_to_variant_str_opt("text/plain"), Some(MimeType::Text));
+    assert_eq!(MimeType::value_to_variant_str_opt("video/mp4"), None);
+    assert_eq!(MimeType::value_to_variant_str("audio/ogg"), MimeType::Ogg);
+    assert!(std::panic::catch_unwind(|| MimeType::value_to_variant_str("video/mp4")).is_err());
+}
+
+#[test]
+fn test_value_in() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let textures = ["grass", "stone", "water"];
+    assert_eq!(NumberValue::Zero.value_in(&textures), Some(&"grass"));
+    assert_eq!(NumberValue::Second.value_in(&textures), Some(&"water"));
+    assert_eq!(NumberValue::Third.value_in(&textures), None);
+}
+
+#[test]
+fn test_from_discriminant_bounded() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+
+    assert_eq!(NumberValue::from_discriminant_bounded(1, 2), Some(NumberValue::First));
+    assert_eq!(NumberValue::from_discriminant_bounded(2, 2), None);
+    assert_eq!(NumberValue::from_discriminant_bounded(3, NumberValue::VARIANT_COUNT), Some(NumberValue::Third));
+    assert_eq!(NumberValue::from_discriminant_bounded(4, 10), None);
+}
+
+#[test]
+fn test_try_value_to_variant() {
+    use indexed_valued_enums::valued_enum::{NoVariantForValue, Valued};
+
+    assert_eq!(NumberValue::try_value_to_variant(&3), Ok(NumberValue::Third));
+    assert_eq!(NumberValue::try_value_to_variant(&9), Err(NoVariantForValue));
+    assert_eq!(NoVariantForValue.to_string(), "There is no variant with such value");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_lookup_errors_as_std_errors() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    fn parse(discriminant: usize, value: u8) -> Result<(NumberValue, NumberValue), Box<dyn std::error::Error>> {
+        Ok((NumberValue::try_from_discriminant(discriminant)?, NumberValue::try_value_to_variant(&value)?))
+    }
+
+    assert_eq!(parse(1, 2).unwrap(), (NumberValue::First, NumberValue::Second));
+    assert!(parse(5, 2).unwrap_err().to_string().contains('5'));
+    assert_eq!(parse(1, 9).unwrap_err().to_string(), "There is no variant with such value");
+}
+
+#[test]
+fn test_value_to_variant_by() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let second = NumberCustom::value_to_variant_by(|value| value.num == 2).unwrap();
+    assert_eq!(second.discriminant(), NumberCustom::Second.discriminant());
+    let ten = NumberCustom::value_to_variant_by(|value| value.num == 10).unwrap();
+    assert_eq!(ten.name, "Ten");
+    assert!(NumberCustom::value_to_variant_by(|value| value.num == 4).is_none());
+}
+
+#[cfg(feature = "value_override")]
+#[test]
+fn test_with_values() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    let doubled = NumberValue::with_values(&[0, 2, 4, 6], || {
+        assert_eq!(Valued::value_ref(&NumberValue::Third), &6);
+        let tripled = NumberValue::with_values(&[0, 3, 6, 9], || Valued::value(&NumberValue::Third));
+        assert_eq!(tripled, 9);
+        Valued::value(&NumberValue::Second)
+    });
+    assert_eq!(doubled, 4);
+    assert_eq!(Valued::value(&NumberValue::Second), 2);
+
+    let panicked = std::panic::catch_unwind(|| NumberValue::with_values(&[5, 5, 5, 5], || panic!()));
+    assert!(panicked.is_err());
+    assert_eq!(Valued::value_opt(&NumberValue::First), Some(1));
+}
+
+#[test]
+fn test_discriminant_of_name() {
+    use indexed_valued_enums::named_enum::{discriminant_of_name_internal, Named};
+
+    const MARS: Option<usize> = discriminant_of_name_internal::<Planets>("Mars");
+    const GRAVITIES: [f32; 3] = [9.807, 3.71, 3.7];
+    assert_eq!(MARS, Some(1));
+    assert_eq!(GRAVITIES[MARS.unwrap()], 3.71);
+    assert_eq!(Keyword::discriminant_of_name("Type"), Some(0));
+    assert_eq!(Keyword::discriminant_of_name("Matc"), None);
+    assert_eq!(Keyword::discriminant_of_name("match"), None);
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(NanoSerJson, NanoDeJson)]
+enum NanoJsonNumber {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+    #[value(2)]
+    Second,
+}
+
+#[test]
+fn test_nano_json_round_trip() {
+    use nanoserde::{DeJson, SerJson};
+
+    let json = NanoJsonNumber::Second.serialize_json();
+    assert_eq!(json, "2");
+    assert_eq!(NanoJsonNumber::deserialize_json(&json).unwrap(), NanoJsonNumber::Second);
+    assert_eq!(NanoJsonNumber::deserialize_json(&NanoJsonNumber::Zero.serialize_json()).unwrap(), NanoJsonNumber::Zero);
+    assert!(NanoJsonNumber::deserialize_json("3").is_err());
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(f32)]
+enum GroupedPlanet {
+    #[value(9.807)]
+    #[variant_group("rocky")]
+    Earth,
+    #[value(24.79)]
+    #[variant_group("gas")]
+    Jupiter,
+    #[value(3.71)]
+    #[variant_group("rocky")]
+    Mars,
+    #[value(10.44)]
+    #[variant_group("gas")]
+    Saturn,
+}
+
+#[test]
+fn test_variant_groups() {
+    const MARS_GROUP: &str = GroupedPlanet::Mars.group();
+    assert_eq!(MARS_GROUP, "rocky");
+    assert_eq!(GroupedPlanet::Jupiter.group(), "gas");
+    assert_eq!(GroupedPlanet::GROUPS, &["rocky", "gas", "rocky", "gas"]);
+    assert_eq!(GroupedPlanet::variants_in_group("rocky").collect::<Vec<_>>(), vec![GroupedPlanet::Earth, GroupedPlanet::Mars]);
+    assert_eq!(GroupedPlanet::variants_in_group("gas").collect::<Vec<_>>(), vec![GroupedPlanet::Jupiter, GroupedPlanet::Saturn]);
+    assert_eq!(GroupedPlanet::variants_in_group("ice").next(), None);
+}
+
+#[test]
+fn test_try_ref_from_discriminant() {
+    use indexed_valued_enums::indexed_enum::{DiscriminantOutOfRange, Indexed};
+
+    let third: &'static NumberValueConstr = NumberValueConstr::try_ref_from_discriminant(3).unwrap();
+    assert_eq!(third, &NumberValueConstr::Third { c: 5, d: 7 });
+    assert_eq!(NumberValueConstr::try_ref_from_discriminant(4),
+               Err(DiscriminantOutOfRange { discriminant: 4, variant_count: 4 }));
+}
+
+#[derive(PartialEq, Debug, Valued)]
+#[enum_valued_as(u8)]
+#[safe_discriminant]
+enum SafeShape {
+    #[value(0)]
+    Point,
+    #[value(1)]
+    #[variant_initialize_uses(1)]
+    Circle(u16),
+    #[value(4)]
+    #[variant_initialize_uses(width: 2, height: 3)]
+    Rectangle { width: u16, height: u16 },
+}
+
+#[test]
+fn test_safe_discriminant() {
+    use indexed_valued_enums::{indexed_enum::Indexed, valued_enum::Valued};
+
+    assert_eq!(SafeShape::Point.discriminant(), 0);
+    assert_eq!(SafeShape::Circle(50).discriminant(), 1);
+    assert_eq!(SafeShape::Rectangle { width: 5, height: 5 }.discriminant(), 2);
+    assert!(SafeShape::iter().enumerate().all(|(index, shape)| shape.discriminant() == index));
+    assert_eq!(SafeShape::Rectangle { width: 5, height: 5 }.value(), 4);
+}
+
+#[derive(Debug, Valued)]
+#[enum_valued_as(&'static str)]
+#[enum_valued_features(BTreeKey)]
+enum Sensor {
+    #[value("Temperature")]
+    #[variant_initialize_uses(0.0)]
+    Temperature(f32),
+    #[value("Humidity")]
+    #[variant_initialize_uses(0.0)]
+    Humidity(f32),
+}
+
+#[test]
+fn test_btree_key() {
+    use std::collections::{BTreeMap, HashSet};
+
+    assert_eq!(Sensor::Temperature(20.5), Sensor::Temperature(-3.0));
+    assert_ne!(Sensor::Temperature(20.5), Sensor::Humidity(20.5));
+    assert!(Sensor::Temperature(99.0) < Sensor::Humidity(0.0));
+
+    let mut readings = BTreeMap::new();
+    readings.insert(Sensor::Humidity(0.4), 1);
+    readings.insert(Sensor::Temperature(20.5), 2);
+    readings.insert(Sensor::Humidity(0.6), 3);
+    assert_eq!(readings.len(), 2);
+    assert_eq!(readings.get(&Sensor::Humidity(0.0)), Some(&3));
+    let first_reading = readings.keys().map(|sensor| match sensor {
+        Sensor::Temperature(celsius) => *celsius,
+        Sensor::Humidity(ratio) => *ratio,
+    }).next();
+    assert_eq!(first_reading, Some(20.5));
+
+    let seen = [Sensor::Humidity(0.1), Sensor::Humidity(0.2)].into_iter().collect::<HashSet<_>>();
+    assert_eq!(seen.len(), 1);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Slot(u8);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(OrdByDiscriminant, HashByDiscriminant)]
+enum Stage {
+    #[value(0)]
+    #[variant_initialize_uses(Slot(0))]
+    Fetch(Slot),
+    #[value(1)]
+    Decode,
+    #[value(2)]
+    #[variant_initialize_uses(slot: Slot(0))]
+    Execute { slot: Slot },
+}
+
+#[test]
+fn test_ord_by_discriminant() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use std::collections::BTreeSet;
+
+    let mut stages = vec![Stage::Execute { slot: Slot(1) }, Stage::Decode, Stage::Fetch(Slot(9)), Stage::Fetch(Slot(2))];
+    stages.sort();
+    assert_eq!(stages, vec![Stage::Fetch(Slot(9)), Stage::Fetch(Slot(2)), Stage::Decode, Stage::Execute { slot: Slot(1) }]);
+    assert!(Stage::Fetch(Slot(200)) < Stage::Decode);
+    assert_eq!(Stage::Fetch(Slot(1)).cmp(&Stage::Fetch(Slot(2))), std::cmp::Ordering::Equal);
+
+    let positions = [Stage::Decode, Stage::Execute { slot: Slot(3) }, Stage::Fetch(Slot(4))].into_iter().collect::<BTreeSet<_>>();
+    assert_eq!(positions.into_iter().map(|stage| stage.discriminant()).collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_hash_by_discriminant() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use std::collections::HashSet;
+    use std::hash::BuildHasher;
+
+    let hasher = std::collections::hash_map::RandomState::new();
+    assert_eq!(hasher.hash_one(Stage::Fetch(Slot(1))), hasher.hash_one(Stage::Fetch(Slot(2))));
+    assert_eq!(hasher.hash_one(Stage::Decode), hasher.hash_one(Stage::Decode.discriminant()));
+
+    let pending = [Stage::Fetch(Slot(1)), Stage::Decode].into_iter().collect::<HashSet<_>>();
+    assert!(pending.contains(&Stage::Fetch(Slot(1))));
+    assert!(pending.contains(&Stage::Decode));
+    assert!(!pending.contains(&Stage::Fetch(Slot(2))));
+    assert!(!pending.contains(&Stage::Execute { slot: Slot(1) }));
+    assert!(pending.iter().all(|stage| stage.discriminant() < 2));
+}
+
+#[derive(PartialEq, Debug, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(BincodeEncode, BincodeDecode)]
+enum BincodeNumber {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+    #[value(2)]
+    Second,
+}
+
+#[test]
+fn test_bincode() {
+    let config = bincode::config::standard();
+    let encoded = bincode::encode_to_vec(BincodeNumber::Second, config).unwrap();
+    assert_eq!(encoded, bincode::encode_to_vec(2u32, config).unwrap());
+    let (decoded, read): (BincodeNumber, usize) = bincode::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!((decoded, read), (BincodeNumber::Second, encoded.len()));
+
+    let out_of_range = bincode::encode_to_vec(3u32, config).unwrap();
+    assert!(matches!(bincode::decode_from_slice::<BincodeNumber, _>(&out_of_range, config),
+                     Err(bincode::error::DecodeError::OtherString(_))));
+}
+
+#[derive(Valued)]
+#[enum_valued_as(i8)]
+#[unvalued_default(-1)]
+enum Score {
+    #[value(0)]
+    Lost,
+    Forfeit,
+    #[value(1)]
+    Tied,
+    #[value(3)]
+    Won,
+    Disqualified,
+}
+
+#[test]
+fn test_variants_with_value() {
+    const FORFEITS: usize = Score::variants_with_value(-1);
+    assert_eq!(FORFEITS, 2);
+    assert_eq!(Score::variants_with_value(3), 1);
+    assert_eq!(Score::variants_with_value(2), 0);
+    assert_eq!(NumberValue::variants_with_value(1), 1);
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(NanoSerBin, NanoDeBin)]
+enum NanoBinNumber {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+}
+
+#[test]
+fn test_nano_bin_errors() {
+    use nanoserde::{DeBin, SerBin};
+
+    let mut bytes = NanoBinNumber::Zero.serialize_bin();
+    bytes.extend(NanoBinNumber::First.serialize_bin());
+    let discriminant_width = bytes.len() / 2;
+    assert_eq!(NanoBinNumber::deserialize_bin(&bytes[discriminant_width..]).unwrap(), NanoBinNumber::First);
+
+    let truncated = NanoBinNumber::deserialize_bin(&bytes[..discriminant_width - 1]).unwrap_err();
+    assert_eq!((truncated.o, truncated.l, truncated.s), (0, discriminant_width, discriminant_width - 1));
+
+    bytes.extend(2usize.serialize_bin());
+    let mut offset = discriminant_width * 2;
+    let out_of_range = NanoBinNumber::de_bin(&mut offset, &bytes).unwrap_err();
+    assert_eq!((out_of_range.o, out_of_range.l, out_of_range.s), (discriminant_width * 2, discriminant_width, bytes.len()));
+}
+
+#[test]
+fn test_nano_de_bin_vec() {
+    use nanoserde::SerBin;
+
+    let variants = vec![NanoBinNumber::First, NanoBinNumber::Zero, NanoBinNumber::First];
+    let bytes = variants.serialize_bin();
+    assert_eq!(NanoBinNumber::de_bin_vec(&bytes).unwrap(), variants);
+    assert_eq!(NanoBinNumber::de_bin_vec(&Vec::<NanoBinNumber>::new().serialize_bin()).unwrap(), vec![]);
+    assert!(NanoBinNumber::de_bin_vec(&bytes[..bytes.len() - 1]).is_err());
+    assert!(NanoBinNumber::de_bin_vec(&vec![0usize, 2].serialize_bin()).is_err());
+    assert!(NanoBinNumber::de_bin_vec(&[usize::MAX.serialize_bin(), 0usize.serialize_bin()].concat()).is_err());
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(NanoSerRon, NanoDeRon)]
+enum NanoRonNumber {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+    #[value(2)]
+    Second,
+}
+
+#[test]
+fn test_nano_ron_round_trip() {
+    use nanoserde::{DeRon, SerRon};
+
+    let ron = NanoRonNumber::First.serialize_ron();
+    assert_eq!(ron, "1");
+    assert_eq!(NanoRonNumber::deserialize_ron(&ron).unwrap(), NanoRonNumber::First);
+    assert_eq!(NanoRonNumber::deserialize_ron(&NanoRonNumber::Second.serialize_ron()).unwrap(), NanoRonNumber::Second);
+    let error = NanoRonNumber::deserialize_ron("3").unwrap_err();
+    assert_eq!(error.msg, "Discriminant 3 doesn't correspond to any variant of NanoRonNumber, as it has 3 variants");
+}
+
+const fn squared(discriminant: usize) -> u16 {
+    (discriminant * discriminant) as u16
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u16)]
+#[enum_value_with(squared)]
+#[unvalued_default(1000)]
+enum Squared {
+    Zero,
+    One,
+    #[value(40)]
+    Two,
+    Three,
+    #[value(1000)]
+    Four,
+}
+
+#[test]
+fn test_enum_value_with() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(Squared::VALUES, &[0, 1, 40, 9, 1000]);
+    assert_eq!(Squared::value_to_variant_opt(&9), Some(Squared::Three));
+    assert_eq!(Squared::value_to_variant_opt(&4), None);
+}
+
+#[derive(Debug, PartialEq)]
+struct Coordinates {
+    x: i16,
+    y: i16,
+}
+
+impl Coordinates {
+    const fn const_eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(Coordinates)]
+#[value_const_eq(Coordinates::const_eq)]
+enum Corner {
+    #[value(Coordinates { x: 0, y: 0 })]
+    BottomLeft,
+    #[value(Coordinates { x: 1, y: 0 })]
+    BottomRight,
+    #[value(Coordinates { x: 1, y: 1 })]
+    TopRight,
+}
+
+#[test]
+fn test_value_to_variant_const() {
+    const TOP_RIGHT: Option<Corner> = Corner::value_to_variant_const(&Coordinates { x: 1, y: 1 });
+    assert_eq!(TOP_RIGHT, Some(Corner::TopRight));
+    assert_eq!(Corner::value_to_variant_const(&Coordinates { x: 0, y: 0 }), Some(Corner::BottomLeft));
+    assert_eq!(Corner::value_to_variant_const(&Coordinates { x: 0, y: 1 }), None);
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Label {
+    text: &'static str,
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(Label)]
+enum Labelled {
+    #[value(Label { text: "first" })]
+    First,
+    #[value(Label { text: "second" })]
+    Second,
+}
+
+#[test]
+fn test_value_cloned() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(Labelled::First.value_cloned(), Label { text: "first" });
+    assert_eq!(Labelled::Second.value_cloned_opt(), Some(Label { text: "second" }));
+    assert_eq!(Labelled::Second.value_ref().text, "second");
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct ShapeId(u32);
+
+impl From<usize> for ShapeId {
+    fn from(discriminant: usize) -> Self {
+        ShapeId(discriminant as u32)
+    }
+}
+
+impl TryFrom<ShapeId> for usize {
+    type Error = core::num::TryFromIntError;
+
+    fn try_from(id: ShapeId) -> Result<Self, Self::Error> {
+        usize::try_from(id.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[discriminant_id(ShapeId)]
+enum Shape {
+    #[value(3)]
+    Triangle,
+    #[value(4)]
+    Square,
+    #[value(5)]
+    Pentagon,
+}
+
+#[test]
+fn test_discriminant_id() {
+    assert_eq!(Shape::Square.to_id(), ShapeId(1));
+    assert_eq!(Shape::from_id(ShapeId(2)), Some(Shape::Pentagon));
+    assert_eq!(Shape::from_id(ShapeId(3)), None);
+    assert_eq!(Shape::from_id(Shape::Triangle.to_id()), Some(Shape::Triangle));
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u32)]
+#[enum_valued_features(DefaultFirst)]
+enum Baud {
+    #[variant_initialize_uses(9600)]
+    #[value(9600)]
+    Standard(u32),
+    #[value(115200)]
+    Fast,
+}
+
+#[test]
+fn test_default_first() {
+    assert_eq!(Baud::default(), Baud::Standard(9600));
+    assert_ne!(Baud::default(), Baud::Fast);
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(&'static str)]
+#[enum_valued_features(Default)]
+enum Compression {
+    #[value("none")]
+    Disabled,
+    #[default]
+    #[variant_initialize_uses(level: 6)]
+    #[value("deflate")]
+    Deflate { level: u8 },
+    #[value("zstd")]
+    Zstd,
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[enum_valued_features(Default)]
+enum Retries {
+    #[value(1)]
+    Once,
+    #[value(3)]
+    Thrice,
+}
+
+#[derive(Copy, Debug, PartialEq, Valued)]
+#[enum_valued_as(u8)]
+#[auto_copy]
+enum Light {
+    #[value(0)]
+    Red,
+    #[value(1)]
+    Amber,
+    #[value(2)]
+    Green,
+}
+
+#[test]
+fn test_auto_copy() {
+    let light = Light::Amber;
+    let copied = light;
+    assert_eq!(light, copied);
+    #[allow(clippy::clone_on_copy)]
+    let cloned = Light::Green.clone();
+    assert_eq!(cloned, Light::Green);
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(Compression::default(), Compression::Deflate { level: 6 });
+    assert_eq!(Retries::default(), Retries::Once);
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(char)]
+#[discriminant_repr(u8)]
+#[enum_valued_features(Delegators)]
+enum Grade {
+    #[value('A')]
+    Excellent,
+    #[value('B')]
+    Good,
+    #[value('C')]
+    Average,
+}
+
+#[test]
+fn test_discriminant_repr() {
+    use indexed_valued_enums::indexed_enum::Indexed;
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(core::mem::size_of::<Grade>(), 1);
+    assert!(core::mem::size_of::<[Grade; 300]>() < core::mem::size_of::<[Planets; 300]>());
+    assert_eq!(Indexed::discriminant(&Grade::Average), 2);
+    assert_eq!(Grade::Good.discriminant(), 1);
+    assert_eq!(Valued::value(&Grade::Good), 'B');
+    assert_eq!(Grade::Average.value(), 'C');
+    assert_eq!(Grade::value_to_variant(&'A'), Grade::Excellent);
+    assert_eq!(Grade::iter().map(|grade| grade.value()).collect::<String>(), "ABC");
+}
+
+#[derive(Debug, PartialEq, Valued)]
+#[enum_valued_as(u32)]
+#[enum_valued_features(Delegators)]
+enum Month {
+    #[value(31)]
+    January,
+    #[value(Self::prev_value(0) + 28)]
+    February,
+    #[value(Self::prev_value(1) + 31)]
+    March,
+    #[value(Self::prev_value(2) + 30)]
+    April,
+}
+
+#[test]
+fn test_prev_value() {
+    use indexed_valued_enums::valued_enum::Valued;
+
+    assert_eq!(Month::February.value(), 59);
+    assert_eq!(Month::April.value(), 120);
+    assert_eq!(Month::value_to_variant(&90), Month::March);
+}
@@ -1,2 +1,5 @@
 mod declarative_macro;
-mod derive_macro;
\ No newline at end of file
+mod derive_macro;
+mod serde_features;
+mod value_index;
+mod sequence_compat;
\ No newline at end of file
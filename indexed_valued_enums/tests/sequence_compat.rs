@@ -0,0 +1,26 @@
+#![cfg(feature = "enum_iterator_compat")]
+
+use enum_iterator::{all, cardinality, first, last, next, previous, Sequence};
+use indexed_valued_enums::create_indexed_valued_enum;
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(SequenceCompat)]
+    enum Weekday valued as &'static str;
+    Monday, "Mon",
+    Tuesday, "Tue",
+    Wednesday, "Wed"
+}
+
+#[test]
+fn test_sequence_compat() {
+    assert_eq!(cardinality::<Weekday>(), 3);
+    assert_eq!(Weekday::CARDINALITY, 3);
+    assert_eq!(first::<Weekday>(), Some(Weekday::Monday));
+    assert_eq!(last::<Weekday>(), Some(Weekday::Wednesday));
+    assert_eq!(next(&Weekday::Monday), Some(Weekday::Tuesday));
+    assert_eq!(next(&Weekday::Wednesday), None);
+    assert_eq!(previous(&Weekday::Tuesday), Some(Weekday::Monday));
+    assert_eq!(previous(&Weekday::Monday), None);
+    assert_eq!(all::<Weekday>().collect::<Vec<_>>(), vec![Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday]);
+}
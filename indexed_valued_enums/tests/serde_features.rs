@@ -0,0 +1,334 @@
+#![cfg(feature = "serde_enums")]
+
+use indexed_valued_enums::create_indexed_valued_enum;
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(DeserializeLenient)]
+    enum LenientNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+#[test]
+fn test_deserialize_lenient() {
+    assert_eq!(serde_json::from_str::<LenientNumber>("1").unwrap(), LenientNumber::First);
+    assert_eq!(serde_json::from_str::<LenientNumber>("[1]").unwrap(), LenientNumber::First);
+    assert_eq!(serde_json::from_str::<LenientNumber>("[2]").unwrap(), LenientNumber::Second);
+    assert!(serde_json::from_str::<LenientNumber>("[]").is_err());
+    assert!(serde_json::from_str::<LenientNumber>("[3]").is_err());
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(DeserializeByNameCaseInsensitive)]
+    enum CaseInsensitivePlanet valued as u8;
+    Earth, 0,
+    Mars, 1,
+    r#Mercury, 2
+}
+
+#[test]
+fn test_deserialize_by_name_case_insensitive() {
+    assert_eq!(serde_json::from_str::<CaseInsensitivePlanet>("\"mars\"").unwrap(), CaseInsensitivePlanet::Mars);
+    assert_eq!(serde_json::from_str::<CaseInsensitivePlanet>("\"Mars\"").unwrap(), CaseInsensitivePlanet::Mars);
+    assert_eq!(serde_json::from_str::<CaseInsensitivePlanet>("\"MARS\"").unwrap(), CaseInsensitivePlanet::Mars);
+    assert_eq!(serde_json::from_str::<CaseInsensitivePlanet>("\"mercury\"").unwrap(), CaseInsensitivePlanet::Mercury);
+    assert!(serde_json::from_str::<CaseInsensitivePlanet>("\"Venus\"").is_err());
+    assert!(serde_json::from_str::<CaseInsensitivePlanet>("1").is_err());
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SerializeNamed, DeserializeNamed)]
+    enum NamedNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+#[test]
+fn test_serialize_and_deserialize_named() {
+    assert_eq!(serde_json::to_string(&NamedNumber::First).unwrap(), "\"First\"");
+    assert_eq!(serde_json::from_str::<NamedNumber>("\"First\"").unwrap(), NamedNumber::First);
+    let serialized = serde_json::to_string(&[NamedNumber::Second, NamedNumber::Zero]).unwrap();
+    assert_eq!(serialized, "[\"Second\",\"Zero\"]");
+    assert_eq!(serde_json::from_str::<Vec<NamedNumber>>(&serialized).unwrap(), vec![NamedNumber::Second, NamedNumber::Zero]);
+    assert!(serde_json::from_str::<NamedNumber>("\"first\"").is_err());
+    assert!(serde_json::from_str::<NamedNumber>("1").is_err());
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(DeserializeFlexible)]
+    enum FlexibleNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+#[test]
+fn test_deserialize_flexible() {
+    assert_eq!(serde_json::from_str::<FlexibleNumber>("1").unwrap(), FlexibleNumber::First);
+    assert_eq!(serde_json::from_str::<FlexibleNumber>("\"First\"").unwrap(), FlexibleNumber::First);
+    assert_eq!(serde_json::from_str::<Vec<FlexibleNumber>>("[2, \"Zero\"]").unwrap(), vec![FlexibleNumber::Second, FlexibleNumber::Zero]);
+    assert!(serde_json::from_str::<FlexibleNumber>("3").is_err());
+    let unknown_name_error = serde_json::from_str::<FlexibleNumber>("\"Third\"").unwrap_err().to_string();
+    assert!(unknown_name_error.starts_with("unknown variant `Third`, expected one of `Zero`, `First`, `Second`"), "{unknown_name_error}");
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SerdeHumanAware)]
+    enum HumanAwareNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+#[test]
+fn test_serde_human_aware() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    assert_eq!(serde_json::to_string(&HumanAwareNumber::First).unwrap(), "\"First\"");
+    assert_eq!(serde_json::from_str::<HumanAwareNumber>("\"Second\"").unwrap(), HumanAwareNumber::Second);
+    assert!(serde_json::from_str::<HumanAwareNumber>("1").is_err());
+
+    assert_tokens(&HumanAwareNumber::First.readable(), &[Token::Str("First")]);
+    assert_tokens(&HumanAwareNumber::First.compact(), &[Token::U64(1)]);
+    assert_tokens(&HumanAwareNumber::Second.compact(), &[Token::U64(2)]);
+    assert_de_tokens_error::<serde_test::Compact<HumanAwareNumber>>(&[Token::U64(3)],
+                                                                    "Deserialized an discriminant that is bigger than the amount of variants");
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SerializeValue, DeserializeValue)]
+    enum Number valued as NumberDescription;
+    Zero, NumberDescription { description: "Zero position", index: 0 },
+    First, NumberDescription { description: "First position", index: 1 },
+    Second, NumberDescription { description: "Second position", index: 2 }
+}
+
+#[derive(PartialEq, serde::Serialize, serde::Deserialize)]
+struct NumberDescription {
+    description: &'static str,
+    index: u16,
+}
+
+#[test]
+fn test_serialize_and_deserialize_value() {
+    let serialized = serde_json::to_string(&Number::First).unwrap();
+    assert_eq!(serialized, "{\"description\":\"First position\",\"index\":1}");
+    assert_eq!(serde_json::from_str::<Number>("{\"description\":\"First position\",\"index\":1}").unwrap(), Number::First);
+    assert_eq!(serde_json::from_str::<Number>(serialized.leak()).unwrap(), Number::First);
+    assert!(serde_json::from_str::<Number>("{\"description\":\"Third position\",\"index\":3}").is_err());
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(Bitset)]
+    enum Permission valued as char;
+    Read, 'r',
+    Write, 'w',
+    Execute, 'x'
+}
+
+#[derive(PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+struct File {
+    #[serde(with = "indexed_valued_enums::serde_compatibility::bitset")]
+    permissions: Vec<Permission>,
+}
+
+#[test]
+fn test_bitset() {
+    assert_eq!(Permission::to_bitset(&[]), 0);
+    assert_eq!(Permission::to_bitset(&[Permission::Execute, Permission::Read, Permission::Read]), 0b101);
+    let unpacked = Permission::from_bitset(0b101).unwrap();
+    assert_eq!(unpacked.into_iter().collect::<Vec<_>>(), vec![Permission::Read, Permission::Execute]);
+    assert!(Permission::from_bitset(0b1000).is_none());
+
+    let file = File { permissions: vec![Permission::Write, Permission::Read] };
+    assert_eq!(serde_json::to_string(&file).unwrap(), "{\"permissions\":3}");
+    assert_eq!(serde_json::from_str::<File>("{\"permissions\":3}").unwrap().permissions, vec![Permission::Read, Permission::Write]);
+    assert!(serde_json::from_str::<File>("{\"permissions\":8}").is_err());
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(CompactDiscriminant, NanoCompactDiscriminant)]
+    enum CompactNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+include!("three_hundred_variants.in");
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(CompactDiscriminant, NanoCompactDiscriminant)]
+    enum CompactThreeHundred valued as u16;
+    variants from three_hundred_variants
+}
+
+#[test]
+fn test_compact_discriminant() {
+    use nanoserde::{DeBin, SerBin};
+
+    let config = bincode::config::standard().with_fixed_int_encoding();
+
+    let encoded = bincode::serde::encode_to_vec(CompactNumber::Second, config).unwrap();
+    assert_eq!(encoded, vec![2]);
+    let (decoded, _): (CompactNumber, usize) = bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, CompactNumber::Second);
+    assert!(bincode::serde::decode_from_slice::<CompactNumber, _>(&[3], config).is_err());
+
+    let encoded = bincode::serde::encode_to_vec(CompactThreeHundred::V299, config).unwrap();
+    assert_eq!(encoded.len(), 2);
+    let (decoded, _): (CompactThreeHundred, usize) = bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, CompactThreeHundred::V299);
+
+    assert_eq!(CompactNumber::Second.serialize_bin(), vec![2]);
+    assert_eq!(CompactNumber::deserialize_bin(&[2]).unwrap(), CompactNumber::Second);
+    assert!(CompactNumber::deserialize_bin(&[3]).is_err());
+    assert_eq!(CompactThreeHundred::V299.serialize_bin().len(), 2);
+    assert_eq!(CompactThreeHundred::deserialize_bin(&CompactThreeHundred::V299.serialize_bin()).unwrap(), CompactThreeHundred::V299);
+
+    assert_eq!(serde_json::to_string(&CompactNumber::First).unwrap(), "1");
+    assert_eq!(serde_json::from_str::<CompactThreeHundred>("256").unwrap(), CompactThreeHundred::V256);
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[serialize_discriminant_as(u8)]
+    enum ByteNumber valued as u8;
+    Zero, 0,
+    First, 1,
+    Second, 2
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[serialize_discriminant_as(u16)]
+    enum WideThreeHundred valued as u16;
+    variants from three_hundred_variants
+}
+
+#[derive(Eq, PartialEq, Debug, indexed_valued_enums::Valued)]
+#[indexed_valued_enums::enum_valued_as(u8)]
+#[serialize_discriminant_as(u32)]
+enum WordNumber {
+    #[value(0)]
+    Zero,
+    #[value(1)]
+    First,
+}
+
+#[test]
+fn test_serialize_discriminant_as() {
+    let config = bincode::config::standard().with_fixed_int_encoding();
+
+    let encoded = bincode::serde::encode_to_vec(ByteNumber::Second, config).unwrap();
+    assert_eq!(encoded, vec![2]);
+    let (decoded, _): (ByteNumber, usize) = bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, ByteNumber::Second);
+    assert!(bincode::serde::decode_from_slice::<ByteNumber, _>(&[3], config).is_err());
+
+    let encoded = bincode::serde::encode_to_vec(WideThreeHundred::V299, config).unwrap();
+    assert_eq!(encoded, 299u16.to_le_bytes());
+    let (decoded, _): (WideThreeHundred, usize) = bincode::serde::decode_from_slice(&encoded, config).unwrap();
+    assert_eq!(decoded, WideThreeHundred::V299);
+
+    let encoded = bincode::serde::encode_to_vec(WordNumber::First, config).unwrap();
+    assert_eq!(encoded, 1u32.to_le_bytes());
+    assert_eq!(serde_json::from_str::<WordNumber>("1").unwrap(), WordNumber::First);
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SerializeUnitVariant)]
+    enum Direction valued as (i8, i8);
+    Up, (0, 1),
+    First, (1, 0),
+    r#Down, (0, -1)
+}
+
+#[derive(serde::Serialize)]
+enum Command {
+    Move(Direction),
+    Stop,
+}
+
+#[test]
+fn test_serialize_unit_variant() {
+    assert_eq!(serde_json::to_string(&Direction::First).unwrap(), "\"First\"");
+    assert_eq!(serde_json::to_string(&Direction::Down).unwrap(), "\"Down\"");
+    let commands = [Command::Move(Direction::Up), Command::Stop];
+    assert_eq!(serde_json::to_string(&commands).unwrap(), "[{\"Move\":\"Up\"},\"Stop\"]");
+
+    use serde_test::{assert_ser_tokens, Token};
+    assert_ser_tokens(&Direction::Down, &[Token::UnitVariant { name: "Direction", variant: "Down" }]);
+}
+
+create_indexed_valued_enum! {
+    #[derive(Eq, PartialEq, Debug)]
+    ##[features(SerializeExternallyTagged)]
+    enum Beacon valued as u8;
+    First, 1,
+    Second, 2,
+    r#Third, 3
+}
+
+#[test]
+fn test_serialize_externally_tagged() {
+    assert_eq!(serde_json::to_string(&Beacon::Second).unwrap(), "{\"Second\":null}");
+    assert_eq!(serde_json::to_string(&Beacon::Third).unwrap(), "{\"Third\":null}");
+    assert_eq!(serde_json::from_str::<Beacon>("{\"Second\":null}").unwrap(), Beacon::Second);
+    assert_eq!(serde_json::from_str::<Beacon>("{\"Third\":null}").unwrap(), Beacon::Third);
+    assert!(serde_json::from_str::<Beacon>("{\"Fourth\":null}").is_err());
+    assert!(serde_json::from_str::<Beacon>("{}").is_err());
+    assert!(serde_json::from_str::<Beacon>("{\"First\":null,\"Second\":null}").is_err());
+    assert!(serde_json::from_str::<Beacon>("\"Second\"").is_err());
+
+    use serde_test::{assert_tokens, Token};
+    assert_tokens(&Beacon::First, &[
+        Token::Map { len: Some(1) }, Token::Str("First"), Token::Unit, Token::MapEnd,
+    ]);
+}
+
+indexed_valued_enums::create_indexed_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(Serialize, Deserialize)]
+    enum Season;
+    Spring,
+    Summer,
+    Autumn,
+    Winter
+}
+
+#[test]
+fn test_indexed_enum_serde() {
+    assert_eq!(serde_json::to_string(&Season::Autumn).unwrap(), "2");
+    assert_eq!(serde_json::from_str::<Season>("3").unwrap(), Season::Winter);
+    assert!(serde_json::from_str::<Season>("4").is_err());
+}
+
+#[derive(PartialEq, Debug, indexed_valued_enums_derive::Valued)]
+#[indexed_valued_enums_derive::enum_valued_as(u8)]
+#[enum_valued_features(Serialize, Deserialize)]
+#[allow_lossy_serialization]
+enum Signal {
+    #[value(0)]
+    Idle,
+    #[value(1)]
+    #[variant_initialize_uses(50)]
+    Busy(u8),
+}
+
+#[test]
+fn test_allow_lossy_serialization() {
+    assert_eq!(serde_json::to_string(&Signal::Busy(80)).unwrap(), "1");
+    assert_eq!(serde_json::from_str::<Signal>("1").unwrap(), Signal::Busy(50));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "phf")]
+
+use indexed_valued_enums::create_indexed_valued_enum;
+use indexed_valued_enums::valued_enum::Valued;
+use indexed_valued_enums_derive::{enum_valued_as, Valued};
+
+create_indexed_valued_enum! {
+    #[derive(PartialEq, Debug)]
+    ##[features(ValueIndex)]
+    enum Keyword valued as &'static str;
+    As, "as", Break, "break", Const, "const", Continue, "continue", Crate, "crate", Else, "else",
+    Enum, "enum", Extern, "extern", False, "false", Fn, "fn", For, "for", If, "if", Impl, "impl",
+    In, "in", Let, "let", Loop, "loop", Match, "match", Mod, "mod", Move, "move", Mut, "mut",
+    Pub, "pub", Ref, "ref", Return, "return", SelfValue, "self", SelfType, "Self", Static, "static",
+    Struct, "struct", Super, "super", Trait, "trait", True, "true", Type, "type", Unsafe, "unsafe",
+    Use, "use", Where, "where", While, "while"
+}
+
+#[derive(PartialEq, Debug, Valued)]
+#[enum_valued_as(u16)]
+#[enum_valued_features(ValueIndex)]
+#[unvalued_default(0u16)]
+enum StatusCode {
+    Unknown,
+    #[value(200u16)]
+    Ok,
+    #[value(404u16)]
+    NotFound,
+    #[value(500u16)]
+    InternalError,
+}
+
+#[test]
+fn test_value_index_matches_linear_search() {
+    let inputs = Keyword::VALUES.iter().copied().chain(["", "self_", "Fn", "whiles", "asd"]);
+    for input in inputs {
+        assert_eq!(Keyword::value_to_variant_indexed_opt(&input), Keyword::value_to_variant_opt(&input));
+    }
+    assert_eq!(Keyword::value_to_variant_indexed(&"Self"), Keyword::SelfType);
+    assert_eq!(Keyword::value_to_variant_indexed_opt(&"loops"), None);
+}
+
+#[test]
+fn test_value_index_derive() {
+    for code in 0..1000 {
+        assert_eq!(StatusCode::value_to_variant_indexed_opt(&code), StatusCode::value_to_variant_opt(&code));
+    }
+    assert_eq!(StatusCode::value_to_variant_indexed(&0), StatusCode::Unknown);
+    assert_eq!(StatusCode::value_to_variant_indexed(&404), StatusCode::NotFound);
+}
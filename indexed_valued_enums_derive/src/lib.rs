@@ -5,13 +5,13 @@ extern crate alloc;
 extern crate proc_macro;
 
 use alloc::format;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use proc_macro::TokenStream;
 
 use proc_macro2::{Ident, Punct};
-use quote::quote;
-use syn::{Attribute, DataEnum, DeriveInput, Error, parse_macro_input, Type, Variant};
+use quote::{quote, ToTokens};
+use syn::{Attribute, DataEnum, DeriveInput, Error, Lit, LitStr, Meta, MetaNameValue, NestedMeta, parse_macro_input, Type, Variant};
 use syn::Data;
 use syn::parse::ParseStream;
 use utils::{ExpectElseOption, ExpectElseResult};
@@ -29,9 +29,22 @@ use utils::{ExpectElseOption, ExpectElseResult};
 /// |---|---|---|
 /// | #[enum_valued_as(type)] | Enum | Type of your variant’s values. <br><br> This is silently an Attribute macro that adds ‘#[repr(usize)]’ to your enum, rather than a simple attribute, it’s used is also reserved if in the future new features should be born that require to modify your enum silently, if so, changes will appear both here and in the [enum_valued_as] documentation.  |
 /// | #[unvalued_default<br>(default value)] | Enum | Default value for variants whose value isn’t specified. |
+/// | #[enum_value_with<br>(const function)] | Enum | Const function computing the value of variants whose value isn’t specified from their discriminant, taking precedence over 'unvalued_default'. |
 /// | #[enum_valued_features<br>(extra features)] | Enum | List of extra features, you can find a detailed list of every extra feature in this crate’s index. |
+/// | #[delegator_names<br>(function = "new name", ...)] | Enum | Renames the functions generated by the 'Delegators' features, like #[delegator_names(value = "val", discriminant = "idx")], this avoids collisions with your own functions. |
+/// | #[value_const_eq<br>(const function)] | Enum | Const function comparing two values, like 'const fn eq(&Value, &Value) -> bool', giving the enum the const function 'value_to_variant_const', which finds variants from values at compile time even when values aren't primitives. |
+/// | #[discriminant_id<br>(ID type)] | Enum | Type of your domain's IDs implementing 'From<usize>' and 'TryInto<usize>', giving the enum the functions 'to_id' and 'from_id' that convert between variants and IDs through their discriminants. |
+/// | #[enum_value_by_ref] | Enum | Makes the 'value' function of the 'Delegators' feature return a static reference to the value rather than a copy, the copy is then given by 'value_owned' when the value implements Copy. |
+/// | #[const_trait_impls] | Enum | Implements the traits 'ConstIndexed' and 'ConstValued' as 'impl const', letting their functions be called in const contexts, this is the same as the 'ConstTraitImpls' feature and requires a nightly compiler and the feature 'const_trait'. |
+/// | #[allow_lossy_serialization] | Enum | Allows using serialization features like 'Serialize' or 'NanoSerBin' on enums with fields, which otherwise fail to compile as these features de/serialize variants without their fields. |
+/// | #[discriminant_repr<br>(unsigned integer type)] | Enum | Type of your enum's discriminant in memory instead of usize, like #[discriminant_repr(u8)], making your enum smaller, while functions still give discriminants as usize. |
+/// | #[serialize_discriminant_as<br>(unsigned integer type)] | Enum | Implements serde's Serialize and Deserialize using your enum's discriminant written as this type, like #[serialize_discriminant_as(u8)], failing to compile if your enum has more variants than this type can tell apart. |
+/// | #[auto_copy] | Enum | Implements Clone by copying variants from 'VARIANTS', just like the 'Clone' feature, failing to compile if any variant has fields, so your enum can also derive Copy, which should be added as #[derive(Copy)]. |
+/// | #[safe_discriminant] | Enum | Makes the 'discriminant' function of the 'Indexed' trait match the variant rather than reading it from the enum's memory, avoiding its unsafe read at the cost of being slightly slower. |
 /// | #[value(This variant’s value)] | Variant | Value this variant will resolve to when calling the ‘value’ function. |
 /// | #[variant_initialize_uses<br>(Field default values)] | Variant with fields | Specifies the contents of the field of said. |
+/// | #[default] | Variant | Variant given by 'Default::default' when using the 'Default' feature, when no variant is marked, the variant with the lowest discriminant is given instead, and marking more than one variant fails to compile. |
+/// | #[variant_group("group")] | Variant | Group this variant belongs to, when used, every variant must have a group, and the enum gets the const GROUPS and the functions 'group' and 'variants_in_group'. |
 ///
 /// <br>
 ///
@@ -71,6 +84,27 @@ use utils::{ExpectElseOption, ExpectElseResult};
 /// ```
 /// <br>
 ///
+/// **Avoid name collisions**: The 'Delegators' features generate functions like 'value',
+/// 'discriminant' or 'label' directly on your enum, if you already have functions with these
+/// names, the compiler will complain about duplicate definitions, as this macro can't see your own
+/// functions. In this case you can rename the generated functions with the attribute
+/// #[delegator_names(*function* = "*new name*", ...)].<br>
+///
+/// ```rust ignore
+/// ...
+/// #[enum_valued_features(Delegators)]
+/// #[delegator_names(value = "val", discriminant = "idx")]
+/// pub enum MyEnum{
+///     ...
+/// }
+///
+/// impl MyEnum{
+///     /// This won't collide with the delegator, as it is now named 'val'
+///     pub fn value(&self) -> String { ... }
+/// }
+/// ```
+/// <br>
+///
 /// **Don't repeat yourself**: For variants whose variants values are often repeated or irrelevant
 /// you can use the attribute #[unvalued_default(*Your default value*)] which will make all these
 /// unvalued variants to resolve into said value.<br>
@@ -86,9 +120,45 @@ use utils::{ExpectElseOption, ExpectElseResult};
 ///     Variant2,
 /// }
 /// ```
+///
+/// When values follow a formula instead, you can use the attribute #[enum_value_with(*const fn*)],
+/// which computes the value of each unvalued variant by calling this function with it's
+/// discriminant, variants with a #[value(...)] keep their own value, and the formula takes
+/// precedence over #[unvalued_default(...)].<br>
+///
+/// ```rust ignore
+/// ...
+/// #[enum_value_with(squared)]
+/// pub enum MyEnum{
+///     /// This variant's value will resolve to squared(0), as a value it is not specified.
+///     Variant1,
+///     /// This variant's value will resolve to 10 as it is specified.
+///     #[value(10)]
+///     Variant2,
+/// }
+///
+/// const fn squared(discriminant: usize) -> u16 { (discriminant * discriminant) as u16 }
+/// ```
+///
+/// Values can also be built from the values of previous variants by calling
+/// Self::prev_value(*discriminant*), which is useful for cumulative tables like running sums, note
+/// that it can only take the values of variants declared before, as values are computed in order,
+/// so taking the value of the same or a later variant fails to compile, and that within these
+/// values 'Self' doesn't refer to your enum.<br>
+///
+/// ```rust ignore
+/// ...
+/// pub enum MyEnum{
+///     #[value(31)]
+///     January,
+///     /// This variant's value will resolve to 31 + 28.
+///     #[value(Self::prev_value(0) + 28)]
+///     February,
+/// }
+/// ```
 /// <br>
 ///
-/// **Variant's with fields can be added too!** Unlike the declarative macro, this one is compatible
+/// **Variant's with fields can be added too!** Like the declarative macro, this one is compatible
 /// with variants with fields, be them named or unnamed, but they have a downside: since the 
 /// [Indexed::from_discriminant] function must return a constant value for each variants, we also 
 /// need to create those variants with values at compile, when this situation arises you have two 
@@ -116,6 +186,11 @@ use utils::{ExpectElseOption, ExpectElseResult};
 /// ```
 /// <br>
 ///
+/// **Literal values**: When every value is an integer, byte, char or bool literal, like #[value(3)],
+/// your enum also gets the const function 'variants_with_value', giving how many variants have a
+/// value, which lets const code reason about repeated values, if any value isn't such a literal,
+/// this function simply isn't generated.<br>
+///
 /// ## Examples
 ///
 /// A simple example using this macro could look like this:
@@ -167,7 +242,7 @@ use utils::{ExpectElseOption, ExpectElseResult};
 ///
 ///
 /// ```
-#[proc_macro_derive(Valued, attributes(enum_valued_features, unvalued_default, variant_initialize_uses, value))]
+#[proc_macro_derive(Valued, attributes(enum_valued_features, unvalued_default, enum_value_with, enum_value_by_ref, value_const_eq, discriminant_id, const_trait_impls, allow_lossy_serialization, discriminant_repr, serialize_discriminant_as, auto_copy, delegator_names, default, safe_discriminant, variant_initialize_uses, value, variant_group))]
 pub fn derive_macro_describe(input: TokenStream) -> TokenStream {
     /*    let cloned_input = input.clone();
     print_info("Derive input info", &*format!("{:#?}\n", parse_macro_input!(cloned_input as DeriveInput)));*/
@@ -187,6 +262,8 @@ fn derive_enum(attrs: &Vec<Attribute>, enum_name: &Ident, my_enum: DataEnum) ->
                           #[derive(Valued)]\n#[enum_valued_as(*your type*)]\nenum {enum_name} {{\n\t...\n}} "));
     let unvalued_default = find_attribute(&attrs, "unvalued_default")
         .map(|unvalued_default| { &unvalued_default.tokens });
+    let value_formula = find_attribute(attrs, "enum_value_with")
+        .map(|formula_attr| proc_macro2::TokenStream::from(extract_token_stream_of_attribute(formula_attr)));
 
     let features = find_attribute(&attrs, "enum_valued_features")
         .map(|features_attr| features_attr.parse_args_with(parse_separated_idents)
@@ -194,17 +271,97 @@ fn derive_enum(attrs: &Vec<Attribute>, enum_name: &Ident, my_enum: DataEnum) ->
                 Your enum's should look like this, like:\n\n\
                   #[derive(Valued)]\n#[enum_valued_as({valued_as:?})]\n#[value(...)] <------- Your features here, like 'Delegators, ValueToVariantDelegators...' \nenum {enum_name} {{\n\t...\n}} ")))
         .unwrap_or(Vec::new());
+    let features = if find_attribute(attrs, "enum_value_by_ref").is_some() {
+        features.into_iter()
+            .map(|feature| if feature.eq("Delegators") { Ident::new("DelegatorsValueByRef", feature.span()) } else { feature })
+            .collect()
+    } else {
+        features
+    };
+    // Values are indexed by the variant's position, so discriminants set manually would misplace them
+    if let Some((variant, (_, discriminant))) = my_enum.variants.iter()
+        .find_map(|variant| variant.discriminant.as_ref().map(|discriminant| (variant, discriminant))) {
+        let variant_name = &variant.ident;
+        return Error::new_spanned(discriminant, format!("The variant {variant_name} of enum {enum_name} has an explicit discriminant, \
+            but variants of valued enums can't set their discriminants manually, as their discriminants must be their positions \
+            to find their values in 'VALUES', consider removing '= {}'", quote!(#discriminant)))
+            .to_compile_error().into();
+    }
+    // The Default feature gives the variant marked with #[default], so there can't be more than one
+    let mut default_variants = my_enum.variants.iter().enumerate()
+        .filter(|(_, variant)| find_attribute(&variant.attrs, "default").is_some());
+    let default_discriminant = default_variants.next().map(|(discriminant, _)| discriminant).unwrap_or(0);
+    if let Some((_, variant)) = default_variants.next() {
+        let variant_name = &variant.ident;
+        return Error::new_spanned(find_attribute(&variant.attrs, "default"), format!("The variant {variant_name} of enum {enum_name} \
+            is marked as #[default], but another variant is already marked as #[default], only one variant can be the default one"))
+            .to_compile_error().into();
+    }
+    let has_fields = my_enum.variants.iter().any(|variant| !variant.fields.is_empty());
+    // Copy can only be derived by the user, so #[auto_copy] just ensures it's derivable and clones
+    // variants by their discriminant
+    let mut features = features;
+    if let Some(auto_copy) = find_attribute(attrs, "auto_copy") {
+        if has_fields {
+            return Error::new_spanned(auto_copy, format!("The attribute #[auto_copy] of enum {enum_name} requires every variant \
+                to be field-less, but some of its variants have fields, consider removing #[auto_copy]"))
+                .to_compile_error().into();
+        }
+        if !features.iter().any(|feature| feature.eq("Clone")) {
+            features.push(Ident::new("Clone", proc_macro2::Span::call_site()));
+        }
+    }
+    if has_fields && find_attribute(attrs, "allow_lossy_serialization").is_none() {
+        if let Some(serialize_discriminant_as) = find_attribute(attrs, "serialize_discriminant_as") {
+            return Error::new_spanned(serialize_discriminant_as, format!("The attribute #[serialize_discriminant_as(...)] of enum {enum_name} \
+                de/serializes its variants without their fields, losing their contents as some of its variants have fields, if this \
+                is intended, add the attribute #[allow_lossy_serialization] to {enum_name}"))
+                .to_compile_error().into();
+        }
+        if let Some(lossy_feature) = features.iter().find(|feature| LOSSY_SERIALIZATION_FEATURES.iter().any(|lossy| feature.eq(lossy))) {
+            return Error::new_spanned(lossy_feature, format!("The feature {lossy_feature} of enum {enum_name} de/serializes its variants \
+                without their fields, losing their contents as some of its variants have fields, if this is intended, add the \
+                attribute #[allow_lossy_serialization] to {enum_name}"))
+                .to_compile_error().into();
+        }
+    }
+    let delegator_names = match find_attribute(attrs, "delegator_names")
+        .map(|names_attr| parse_delegator_names(names_attr, enum_name))
+        .transpose() {
+        Ok(delegator_names) => delegator_names.unwrap_or_default(),
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let has_value_index = features.iter().any(|feature| feature.eq("ValueIndex"));
+    let has_str_reverse_lookup = features.iter().any(|feature| feature.eq("StrReverseLookup"));
+    let default_variant = features.iter().any(|feature| feature.eq("Default")).then(|| {
+        let default_discriminant = proc_macro2::Literal::usize_unsuffixed(default_discriminant);
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(default variant #enum_name; #default_discriminant);)
+    });
+    let (delegators, features): (Vec<_>, Vec<_>) = features.into_iter()
+        .filter(|feature| !feature.eq("Default") && !feature.eq("StrReverseLookup"))
+        .partition(|feature| DELEGATORS_FUNCTIONS_NAMES.iter().any(|(delegator, _)| feature.eq(delegator)));
+    let delegators = delegators.into_iter()
+        .map(|feature| delegators_with_names(feature, &delegator_names, enum_name, &valued_as))
+        .collect::<Result<Vec<_>, _>>();
+    let delegators = match delegators {
+        Ok(delegators) => delegators,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     let mut variants = Vec::with_capacity(my_enum.variants.len());
     let mut variants_values = Vec::with_capacity(my_enum.variants.len());
     let mut variants_fields_initializer = Vec::with_capacity(my_enum.variants.len());
 
-    my_enum.variants.iter().for_each(|variant| {
+    my_enum.variants.iter().enumerate().for_each(|(index, variant)| {
         //print_info("variants", &format!("{variant:#?}"));
         let variant_name = &variant.ident;
         let variant_value = find_attribute(&variant.attrs, "value")
-            .map(|variants_value_attr| { &variants_value_attr.tokens })
-            .or_else(|| unvalued_default.clone())
+            .map(|variants_value_attr| variants_value_attr.tokens.clone())
+            .or_else(|| value_formula.as_ref().map(|value_formula| {
+                let index = proc_macro2::Literal::usize_unsuffixed(index);
+                quote!((#value_formula(#index)))
+            }))
+            .or_else(|| unvalued_default.cloned())
             .expect_else(|| format!("Could not find value for variant {variant_name}\n\n Consider adding a value like:\n\n\
                                           #[value(...)] <------- Your value of type {valued_as:?}\n{variant_name}\n\n\n Or add a default value for variants without values, like\n\n\
                                           #[derive(Valued)]\n#[enum_valued_as(*your type*)]\n#[unvalued_default(...)] <------- Your value of type\nenum {{\n\t...\n}} ", ));
@@ -232,14 +389,240 @@ fn derive_enum(attrs: &Vec<Attribute>, enum_name: &Ident, my_enum: DataEnum) ->
         );
     });
 
+    // Values calling Self::prev_value(...) are each computed in their own impl of a helper type, where
+    // prev_value chains to the impls of earlier variants, as values can't read VALUES while building it
+    let prev_values = variants_values.iter().any(|value| uses_ident(value, "prev_value")).then(|| {
+        let helper = quote::format_ident!("__{}PrevValues", enum_name);
+        let impls = variants_values.iter().enumerate().map(|(index, value)| {
+            let index_literal = proc_macro2::Literal::usize_unsuffixed(index);
+            let prev_value = match index.checked_sub(1).map(proc_macro2::Literal::usize_unsuffixed) {
+                Some(previous) => quote!(
+                    if index == #previous { #helper::<#previous>::VALUE } else { #helper::<#previous>::prev_value(index) }
+                ),
+                None => quote!(panic!("prev_value can only take the value of variants declared before this one")),
+            };
+            quote! {
+                impl #helper<#index_literal> {
+                    #[allow(dead_code)]
+                    const fn prev_value(index: usize) -> #valued_as { #prev_value }
+                    const VALUE: #valued_as = #value;
+                }
+            }
+        });
+        quote! {
+            #[doc(hidden)]
+            struct #helper<const DISCRIMINANT: usize>;
+            #(#impls)*
+        }
+    });
+    if prev_values.is_some() {
+        let helper = quote::format_ident!("__{}PrevValues", enum_name);
+        variants_values.iter_mut().enumerate().for_each(|(index, value)| {
+            let index = proc_macro2::Literal::usize_unsuffixed(index);
+            *value = quote!((#helper::<#index>::VALUE));
+        });
+    }
+
+    // Attributes holding each variant's value as written, or None when it's computed by the
+    // #[enum_value_with] formula
+    let unvalued_default = find_attribute(attrs, "unvalued_default").filter(|_| value_formula.is_none());
+    let written_values = my_enum.variants.iter()
+        .map(|variant| find_attribute(&variant.attrs, "value").or(unvalued_default))
+        .collect::<Vec<_>>();
+
+    // The value index requires the values without their surrounding parenthesis, as phf only
+    // accepts literals as keys
+    let value_index = has_value_index.then(|| {
+        let index_values = written_values.iter()
+            .map(|value_attr| value_attr.expect_else(|| format!("The feature ValueIndex of enum {enum_name} requires every value to be a literal, so they can't be computed by #[enum_value_with(...)]")))
+            .map(|value_attr| proc_macro2::TokenStream::from(extract_token_stream_of_attribute(value_attr)));
+        quote!(indexed_valued_enums::create_value_index !(#enum_name #valued_as; #(#variants, #index_values),*);)
+    });
+
+    // Counting variants by value in a const fn requires matching the values as patterns, so it's
+    // only generated when every value is a literal that can be matched
+    let literal_values = written_values.iter()
+        .map(|value_attr| value_attr.and_then(|value_attr| value_attr.parse_args_with(parse_matchable_literal).ok()))
+        .collect::<Option<Vec<_>>>();
+    let variants_with_value = literal_values.map(|literal_values|
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(variants with value #enum_name #valued_as; #(#literal_values),*);)
+    );
+
+    // The values are sorted while expanding, so variants can be found by binary search on them
+    let str_reverse_lookup = if has_str_reverse_lookup {
+        let mut str_values = Vec::with_capacity(written_values.len());
+        for (discriminant, value_attr) in written_values.iter().enumerate() {
+            let value = value_attr.and_then(|value_attr| value_attr.parse_args::<LitStr>().ok())
+                .expect_else(|| format!("The feature StrReverseLookup of enum {enum_name} requires every value to be a string literal, \
+                    like #[value(\"text\")], so they can be sorted when expanding the enum"));
+            str_values.push((value, discriminant));
+        }
+        str_values.sort_by_key(|(value, _)| value.value());
+        if let Some(repeated) = str_values.windows(2).find(|pair| pair[0].0.value() == pair[1].0.value()) {
+            let repeated_value = repeated[1].0.value();
+            return Error::new_spanned(&repeated[1].0, format!("The feature StrReverseLookup of enum {enum_name} requires every value \
+                to be unique, but the value \"{repeated_value}\" is repeated"))
+                .to_compile_error().into();
+        }
+        let (sorted_values, discriminants): (Vec<_>, Vec<_>) = str_values.into_iter()
+            .map(|(value, discriminant)| (value, proc_macro2::Literal::usize_unsuffixed(discriminant)))
+            .unzip();
+        Some(quote!(indexed_valued_enums::create_indexed_valued_enum !(str reverse lookup #enum_name; #(#sorted_values => #discriminants),*);))
+    } else {
+        None
+    };
+
+    let value_const_eq = find_attribute(attrs, "value_const_eq").map(|const_eq_attr| {
+        let const_eq = const_eq_attr.parse_args::<syn::Path>()
+            .expect_else(|_| format!("Wrong syntax of attribute '#[value_const_eq(...)]' of enum {enum_name}, it must contain the path to a const function comparing two values, like #[value_const_eq(Point::const_eq)]"));
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(value const eq #enum_name #valued_as; #const_eq);)
+    });
+
+    let discriminant_id = find_attribute(attrs, "discriminant_id").map(|id_attr| {
+        let id_type = id_attr.parse_args::<Type>()
+            .expect_else(|_| format!("Wrong syntax of attribute '#[discriminant_id(...)]' of enum {enum_name}, it must contain the type of your IDs, like #[discriminant_id(PlanetId)]"));
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(discriminant id #enum_name; #id_type);)
+    });
+
+    let const_trait_impls = find_attribute(attrs, "const_trait_impls").is_some().then(||
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(process feature #enum_name, #valued_as; ConstTraitImpls);)
+    );
+
+    let variant_groups = my_enum.variants.iter()
+        .map(|variant| find_attribute(&variant.attrs, "variant_group"))
+        .collect::<Vec<_>>();
+    let variant_groups = variant_groups.iter().any(Option::is_some).then(|| {
+        let groups = variant_groups.iter().zip(my_enum.variants.iter())
+            .map(|(group, variant)| {
+                let variant_name = &variant.ident;
+                let group = group.expect_else(|| format!("Could not find the group of variant {variant_name}, when a variant has a group, every variant of {enum_name} must have one, like:\n\n\
+                                          #[variant_group(\"...\")] <------- Your group\n{variant_name}"));
+                group.parse_args::<LitStr>()
+                    .expect_else(|_| format!("Wrong syntax of attribute '#[variant_group(...)]' on variant {variant_name}, it must contain the name of the group as a string, like #[variant_group(\"rocky\")]"))
+            });
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(variant groups #enum_name; #(#groups),*);)
+    });
+
+    // The safe discriminant gives each variant its position, as the declarative macro can't count
+    let safe_discriminant = find_attribute(attrs, "safe_discriminant").is_some().then(|| {
+        let discriminants = (0..variants.len()).map(proc_macro2::Literal::usize_unsuffixed);
+        quote!([safe discriminant #(#variants => #discriminants),*];)
+    });
+
+    let discriminant_repr = find_attribute(attrs, "discriminant_repr").map(|repr_attr| {
+        let repr = repr_attr.parse_args::<Ident>()
+            .expect_else(|_| format!("Wrong syntax of attribute '#[discriminant_repr(...)]' of enum {enum_name}, it must contain an unsigned integer type, like #[discriminant_repr(u8)]"));
+        if !DISCRIMINANT_REPRS.iter().any(|discriminant_repr| repr.eq(discriminant_repr)) {
+            return Err(Error::new_spanned(&repr, format!("The discriminant of enum {enum_name} can't be represented as {repr}, \
+                #[discriminant_repr(...)] only accepts u8, u16, u32, u64 or usize")));
+        }
+        Ok(quote!([discriminant repr #repr];))
+    });
+    let discriminant_repr = match discriminant_repr.transpose() {
+        Ok(discriminant_repr) => discriminant_repr,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let serialize_discriminant_as = find_attribute(attrs, "serialize_discriminant_as").map(|width_attr| {
+        let width = width_attr.parse_args::<Ident>()
+            .expect_else(|_| format!("Wrong syntax of attribute '#[serialize_discriminant_as(...)]' of enum {enum_name}, it must contain an unsigned integer type, like #[serialize_discriminant_as(u8)]"));
+        quote!(indexed_valued_enums::create_indexed_valued_enum !(serialize discriminant as #enum_name; #width);)
+    });
+
     let output = quote! {
-                indexed_valued_enums::create_indexed_valued_enum !(impl traits #enum_name #valued_as; #(#variants, #variants_values #variants_fields_initializer),*);
+                indexed_valued_enums::create_indexed_valued_enum !(impl traits #enum_name #valued_as; #safe_discriminant #discriminant_repr #(#variants, #variants_values #variants_fields_initializer),*);
                 indexed_valued_enums::create_indexed_valued_enum !(process features #enum_name, #valued_as; #(#features);*);
+                #(#delegators)*
+                #value_index
+                #str_reverse_lookup
+                #variant_groups
+                #variants_with_value
+                #prev_values
+                #value_const_eq
+                #discriminant_id
+                #const_trait_impls
+                #serialize_discriminant_as
+                #default_variant
             };
     utils::print_info(|| "output_str", || format!("{:#?}", output.to_string()));
     output.into()
 }
 
+/// Types accepted by #[discriminant_repr(...)], as discriminants are read back as unsigned integers
+/// of the same size.
+const DISCRIMINANT_REPRS: [&str; 5] = ["u8", "u16", "u32", "u64", "usize"];
+
+/// Features de/serializing variants without their fields, which can only be used on enums with
+/// fields when attributed with #[allow_lossy_serialization].
+const LOSSY_SERIALIZATION_FEATURES: [&str; 23] = ["Serialize", "Deserialize", "CompactDiscriminant",
+    "DeserializeLenient", "SerializeValue", "DeserializeValue", "SerializeNamed", "SerializeUnitVariant",
+    "SerializeExternallyTagged", "DeserializeNamed", "DeserializeByNameCaseInsensitive", "DeserializeFlexible", "SerdeHumanAware",
+    "NanoSerBin", "NanoDeBin", "NanoCompactDiscriminant", "SerJson", "NanoSerJson", "NanoDeJson", "NanoSerRon", "NanoDeRon",
+    "BincodeEncode", "BincodeDecode"];
+
+/// Names of the functions generated by each of the delegator features.
+const DELEGATORS_FUNCTIONS_NAMES: [(&str, &[&str]); 2] = [
+    ("Delegators", &["discriminant", "from_discriminant_opt", "from_discriminant", "eq_discriminant",
+        "first", "last", "value_opt", "value", "value_ref_opt", "value_ref", "neighbors", "dispatch_value", "label"]),
+    ("DelegatorsValueByRef", &["discriminant", "from_discriminant_opt", "from_discriminant", "eq_discriminant",
+        "first", "last", "value_opt", "value", "value_owned_opt", "value_owned", "neighbors", "dispatch_value", "label"]),
+];
+
+fn parse_delegator_names(names_attr: &Attribute, enum_name: &Ident) -> Result<Vec<(String, Ident)>, Error> {
+    let wrong_syntax = |tokens: &dyn ToTokens| Error::new_spanned(tokens, format!("Wrong syntax of attribute '#[delegator_names(...)]', it must contain a list of the delegator functions to rename along their new names, like:\n\n\
+                  #[derive(Valued)]\n#[enum_valued_as(*your type*)]\n#[delegator_names(value = \"val\", discriminant = \"idx\")]\nenum {enum_name} {{\n\t...\n}} "));
+    let Ok(Meta::List(names_list)) = names_attr.parse_meta() else { return Err(wrong_syntax(names_attr)) };
+    names_list.nested.iter()
+        .map(|name| match name {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit: Lit::Str(new_name), .. })) => {
+                let function_name = path.get_ident().ok_or_else(|| wrong_syntax(path))?.to_string();
+                if names_list.nested.iter().filter(|other_name| matches!(other_name, NestedMeta::Meta(Meta::NameValue(other_name)) if other_name.path.is_ident(&function_name))).count() > 1 {
+                    return Err(Error::new_spanned(path, format!("Attribute '#[delegator_names(...)]' of enum {enum_name} renames '{function_name}' more than once")));
+                }
+                if !DELEGATORS_FUNCTIONS_NAMES.iter().any(|(_, functions)| functions.contains(&&*function_name)) {
+                    return Err(Error::new_spanned(path, format!("Attribute '#[delegator_names(...)]' of enum {enum_name} tries to rename '{function_name}', but there isn't any delegator function with this name")));
+                }
+                let new_name = new_name.parse::<Ident>()
+                    .map_err(|_| Error::new_spanned(new_name, format!("'{}' is not a valid function name", new_name.value())))?;
+                Ok((function_name, new_name))
+            }
+            _ => Err(wrong_syntax(name)),
+        })
+        .collect()
+}
+
+fn delegators_with_names(feature: Ident, delegator_names: &[(String, Ident)], enum_name: &Ident, valued_as: &Type) -> Result<proc_macro2::TokenStream, Error> {
+    let (_, functions) = DELEGATORS_FUNCTIONS_NAMES.iter().find(|(delegator, _)| feature.eq(delegator)).unwrap();
+    let functions = functions.iter()
+        .map(|function| {
+            let function_ident = Ident::new(function, feature.span());
+            let new_name = delegator_names.iter()
+                .find(|(renamed_function, _)| renamed_function.eq(function))
+                .map(|(_, new_name)| new_name.clone())
+                .unwrap_or_else(|| function_ident.clone());
+            (function_ident, new_name)
+        })
+        .collect::<Vec<_>>();
+    for (index, (function, new_name)) in functions.iter().enumerate() {
+        if let Some((other_function, other_name)) = functions[..index].iter().find(|(_, other_name)| other_name.eq(new_name)) {
+            // Points to the renamed one, as the other keeps its default name
+            let renamed = if new_name.eq(function) { other_name } else { new_name };
+            return Err(Error::new_spanned(renamed, format!("The functions '{other_function}' and '{function}' generated by the feature '{feature}' \
+                of enum {enum_name} would both be named '{new_name}', rename one of them using the attribute '#[delegator_names(...)]'")));
+        }
+    }
+    let (functions, new_names): (Vec<_>, Vec<_>) = functions.into_iter().unzip();
+    Ok(quote!(indexed_valued_enums::create_indexed_valued_enum !(delegators #enum_name, #valued_as; #feature [#(#functions)*] [#(#functions => #new_names),*]);))
+}
+
+fn uses_ident(tokens: &proc_macro2::TokenStream, ident: &str) -> bool {
+    tokens.clone().into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(token_ident) => token_ident == ident,
+        proc_macro2::TokenTree::Group(group) => uses_ident(&group.stream(), ident),
+        _ => false,
+    })
+}
+
 fn extract_token_stream_of_attribute(variants_value_attr: &Attribute) -> TokenStream {
     let mut token_stream = None;
     let _ = variants_value_attr.parse_args_with(|input: ParseStream| {
@@ -261,6 +644,20 @@ fn fields_as_const_defaults_tokens(variant: &Variant) -> Option<proc_macro2::Tok
     internal_fields_as_default
 }
 
+fn parse_matchable_literal(input: ParseStream) -> Result<proc_macro2::TokenStream, Error> {
+    let minus_sign = input.parse::<Option<syn::Token![-]>>()?;
+    let literal = input.parse::<Lit>()?;
+    let is_matchable = match literal {
+        Lit::Int(_) => true,
+        Lit::Byte(_) | Lit::Char(_) | Lit::Bool(_) => minus_sign.is_none(),
+        _ => false,
+    };
+    if !is_matchable || !input.is_empty() {
+        return Err(Error::new(input.span(), "Not a literal that can be matched in a const fn"));
+    }
+    Ok(quote!(#minus_sign #literal))
+}
+
 fn parse_separated_idents(input: ParseStream) -> Result<Vec<Ident>, Error> {
     let mut idents = Vec::new();
     while !input.is_empty() {
@@ -290,14 +687,21 @@ fn find_attribute<'attr>(attrs: &'attr Vec<Attribute>, attribute_ident: &str) ->
 
 /// Attribute macro used by the 'Valued' derive macro to indicate the type of your variant's values,
 /// it poses as a simple derive macro, but it is used to modify your enum and prepare it for the
-/// Indexed and Valued traits, currently, this only means adding '#[repr(usize)]' to your enum, and
+/// Indexed and Valued traits, currently, this only means adding '#[repr(usize)]' to your enum, or
+/// the type given in the attribute #[discriminant_repr(...)], and
 /// while it is unprobable, this macro is still reserved for manipulating your enum if new features
 /// were to need it, for this reason, this attribute should appear right after #[derive(Valued)] and
 /// before any other attributes.
 #[proc_macro_attribute]
 pub fn enum_valued_as(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let repr = syn::parse::<DeriveInput>(item.clone()).ok()
+        .and_then(|enum_input| find_attribute(&enum_input.attrs, "discriminant_repr")
+            .and_then(|repr_attr| repr_attr.parse_args::<Ident>().ok())
+            .filter(|repr| DISCRIMINANT_REPRS.iter().any(|discriminant_repr| repr.eq(discriminant_repr)))
+            .map(|repr| quote!(#repr)))
+        .unwrap_or_else(|| quote!(usize));
     let item = proc_macro2::TokenStream::from(item);
-    let mut res = quote!(#[repr(usize)]);
+    let mut res = quote!(#[repr(#repr)]);
     res.extend(item);
     res.into()
 }